@@ -1,23 +1,34 @@
 use ansi_to_tui::IntoText;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
 use crossterm::{
+    cursor::MoveTo,
     event::{
         self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers, MouseButton,
         MouseEventKind,
     },
     execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+    terminal::{
+        disable_raw_mode, enable_raw_mode, Clear, ClearType, EnterAlternateScreen,
+        LeaveAlternateScreen,
+    },
 };
 use figment::providers::{Format, Toml};
 use figment::Figment;
+use git2::{DiffFormat, Repository, StatusOptions};
+use image::{imageops::FilterType, RgbaImage};
 use ratatui::{
     backend::CrosstermBackend,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span, Text},
-    widgets::{Block, BorderType, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    widgets::{
+        Block, BorderType, Borders, Cell, List, ListItem, ListState, Paragraph, Row, Table,
+        TableState, Wrap,
+    },
     Terminal,
 };
-use serde::Deserialize;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
 use std::{
     cmp::Ordering,
     collections::{HashMap, HashSet},
@@ -26,10 +37,17 @@ use std::{
     fs, io,
     path::{Path, PathBuf},
     process::Command,
+    io::{Read, Write},
     sync::mpsc,
+    sync::Mutex,
+    sync::OnceLock,
     thread,
-    time::{Duration, Instant},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{Theme as SyntectTheme, ThemeSet};
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
 use tui_input::backend::crossterm::EventHandler;
 use tui_input::{Input, InputRequest};
 
@@ -37,11 +55,22 @@ type AppResult<T> = Result<T, Box<dyn Error>>;
 
 const DATE_WIDTH: usize = 16;
 const DATE_PLACEHOLDER: &str = "---- -- -- --:--";
+const IMAGE_PREVIEW_COLS: u32 = 80;
+const IMAGE_PREVIEW_ROWS: u32 = 40;
 
 #[derive(Clone)]
 struct PreviewData {
     preview: Text<'static>,
     git: Option<Text<'static>>,
+    git_commit_rows: Vec<(usize, String)>,
+    image: Option<ImagePreview>,
+    modified: Option<SystemTime>,
+}
+
+#[derive(Clone)]
+struct ImagePreview {
+    half_block: Text<'static>,
+    kitty_escape: Option<String>,
 }
 
 struct PreviewResult {
@@ -67,10 +96,53 @@ struct TagResult {
     tags: Vec<String>,
 }
 
+#[derive(Clone)]
+struct ContentHit {
+    path: String,
+    line: usize,
+    preview: String,
+}
+
+struct ContentResult {
+    generation: u64,
+    hit: ContentHit,
+}
+
+enum ContentPattern {
+    Literal(String),
+    Regex(Regex),
+}
+
+impl ContentPattern {
+    fn parse(query: &str) -> Option<ContentPattern> {
+        if query.is_empty() {
+            return None;
+        }
+        if query.len() > 1 && query.starts_with('/') && query.ends_with('/') {
+            let inner = &query[1..query.len() - 1];
+            return Regex::new(inner).ok().map(ContentPattern::Regex);
+        }
+        Some(ContentPattern::Literal(query.to_lowercase()))
+    }
+
+    fn is_match(&self, line: &str) -> bool {
+        match self {
+            ContentPattern::Literal(needle) => line.to_lowercase().contains(needle.as_str()),
+            ContentPattern::Regex(re) => re.is_match(line),
+        }
+    }
+}
+
+const CONTENT_DEBOUNCE: Duration = Duration::from_millis(250);
+const CONTENT_MAX_PER_FILE: usize = 20;
+const CONTENT_MAX_TOTAL: usize = 500;
+
 #[derive(Default, Deserialize)]
 struct ConfigFile {
     #[serde(default)]
     paths: Option<ConfigPaths>,
+    #[serde(default)]
+    preview: Option<ConfigPreview>,
 }
 
 #[derive(Default, Deserialize)]
@@ -81,9 +153,435 @@ struct ConfigPaths {
     static_items: Vec<String>,
 }
 
+#[derive(Default, Deserialize)]
+struct ConfigPreview {
+    #[serde(default)]
+    highlight: Option<bool>,
+    #[serde(default)]
+    theme: Option<String>,
+    #[serde(default)]
+    git_status_overlay: Option<bool>,
+}
+
 struct LoadedConfig {
     index_folders: Vec<PathBuf>,
     static_items: Vec<PathBuf>,
+    preview: PreviewConfig,
+}
+
+#[derive(Clone)]
+struct PreviewConfig {
+    highlight_enabled: bool,
+    theme_name: String,
+    git_status_overlay: bool,
+}
+
+impl Default for PreviewConfig {
+    fn default() -> Self {
+        PreviewConfig {
+            highlight_enabled: true,
+            theme_name: "base16-ocean.dark".to_string(),
+            git_status_overlay: true,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+struct Theme {
+    background: Color,
+    text: Color,
+    accent: Color,
+    key: Color,
+    selection: Color,
+    tag: Color,
+    diff_add: Color,
+    diff_del: Color,
+    match_highlight: Color,
+}
+
+impl Theme {
+    fn dark() -> Self {
+        Theme {
+            background: Color::Reset,
+            text: Color::Black,
+            accent: Color::Rgb(72, 166, 255),
+            key: Color::Rgb(150, 150, 150),
+            selection: Color::Rgb(255, 181, 92),
+            tag: Color::Rgb(120, 170, 140),
+            diff_add: Color::Rgb(100, 200, 120),
+            diff_del: Color::Rgb(220, 80, 80),
+            match_highlight: Color::Rgb(72, 166, 255),
+        }
+    }
+
+    fn light() -> Self {
+        Theme {
+            background: Color::White,
+            text: Color::Rgb(30, 30, 30),
+            accent: Color::Rgb(30, 110, 200),
+            key: Color::Rgb(110, 110, 110),
+            selection: Color::Rgb(230, 150, 50),
+            tag: Color::Rgb(60, 130, 90),
+            diff_add: Color::Rgb(30, 140, 60),
+            diff_del: Color::Rgb(180, 50, 50),
+            match_highlight: Color::Rgb(30, 110, 200),
+        }
+    }
+
+    fn solarized() -> Self {
+        Theme {
+            background: Color::Rgb(0, 43, 54),
+            text: Color::Rgb(131, 148, 150),
+            accent: Color::Rgb(38, 139, 210),
+            key: Color::Rgb(88, 110, 117),
+            selection: Color::Rgb(181, 137, 0),
+            tag: Color::Rgb(42, 161, 152),
+            diff_add: Color::Rgb(133, 153, 0),
+            diff_del: Color::Rgb(220, 50, 47),
+            match_highlight: Color::Rgb(38, 139, 210),
+        }
+    }
+
+    fn builtins() -> Vec<(&'static str, Theme)> {
+        vec![
+            ("dark", Theme::dark()),
+            ("light", Theme::light()),
+            ("solarized", Theme::solarized()),
+        ]
+    }
+
+    fn by_name(name: &str) -> Option<Theme> {
+        Theme::builtins()
+            .into_iter()
+            .find(|(candidate, _)| *candidate == name)
+            .map(|(_, theme)| theme)
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::dark()
+    }
+}
+
+#[derive(Default, Deserialize)]
+struct ThemeFile {
+    #[serde(default)]
+    base: Option<String>,
+    #[serde(default)]
+    background: Option<String>,
+    #[serde(default)]
+    text: Option<String>,
+    #[serde(default)]
+    accent: Option<String>,
+    #[serde(default)]
+    key: Option<String>,
+    #[serde(default)]
+    selection: Option<String>,
+    #[serde(default)]
+    tag: Option<String>,
+    #[serde(default)]
+    diff_add: Option<String>,
+    #[serde(default)]
+    diff_del: Option<String>,
+    #[serde(default)]
+    match_highlight: Option<String>,
+}
+
+fn parse_hex_color(raw: &str) -> Option<Color> {
+    let hex = raw.trim().trim_start_matches('#');
+    if hex.len() != 6 {
+        return None;
+    }
+    let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+    let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+    let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+    Some(Color::Rgb(r, g, b))
+}
+
+fn theme_config_path(home: &Path) -> PathBuf {
+    let xdg = env::var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| home.join(".config"));
+    xdg.join("navgator/theme.toml")
+}
+
+fn load_theme() -> Theme {
+    let Ok(home) = home_dir() else {
+        return Theme::default();
+    };
+    let path = theme_config_path(&home);
+    if !path.is_file() {
+        return Theme::default();
+    }
+    let Ok(file) = Figment::from(Toml::file(&path)).extract::<ThemeFile>() else {
+        return Theme::default();
+    };
+
+    let mut theme = file
+        .base
+        .as_deref()
+        .and_then(Theme::by_name)
+        .unwrap_or_default();
+    if let Some(color) = file.background.as_deref().and_then(parse_hex_color) {
+        theme.background = color;
+    }
+    if let Some(color) = file.text.as_deref().and_then(parse_hex_color) {
+        theme.text = color;
+    }
+    if let Some(color) = file.accent.as_deref().and_then(parse_hex_color) {
+        theme.accent = color;
+    }
+    if let Some(color) = file.key.as_deref().and_then(parse_hex_color) {
+        theme.key = color;
+    }
+    if let Some(color) = file.selection.as_deref().and_then(parse_hex_color) {
+        theme.selection = color;
+    }
+    if let Some(color) = file.tag.as_deref().and_then(parse_hex_color) {
+        theme.tag = color;
+    }
+    if let Some(color) = file.diff_add.as_deref().and_then(parse_hex_color) {
+        theme.diff_add = color;
+    }
+    if let Some(color) = file.diff_del.as_deref().and_then(parse_hex_color) {
+        theme.diff_del = color;
+    }
+    if let Some(color) = file.match_highlight.as_deref().and_then(parse_hex_color) {
+        theme.match_highlight = color;
+    }
+    theme
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum TagPalette {
+    Vivid,
+    Pastel,
+}
+
+impl TagPalette {
+    fn sat_light(self) -> (f32, f32) {
+        match self {
+            TagPalette::Vivid => (0.6, 0.55),
+            TagPalette::Pastel => (0.35, 0.75),
+        }
+    }
+
+    fn by_name(name: &str) -> Option<TagPalette> {
+        match name {
+            "vivid" => Some(TagPalette::Vivid),
+            "pastel" => Some(TagPalette::Pastel),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct TagTheme {
+    overrides: Vec<(String, Color)>,
+    palette: TagPalette,
+}
+
+impl Default for TagTheme {
+    fn default() -> Self {
+        TagTheme {
+            overrides: Vec::new(),
+            palette: TagPalette::Vivid,
+        }
+    }
+}
+
+#[derive(Default, Deserialize)]
+struct TagThemeFile {
+    #[serde(default)]
+    palette: Option<String>,
+    #[serde(default)]
+    colors: HashMap<String, String>,
+}
+
+fn tag_theme_config_path(home: &Path) -> PathBuf {
+    let xdg = env::var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| home.join(".config"));
+    xdg.join("navgator/tags.toml")
+}
+
+fn load_tag_theme() -> TagTheme {
+    let Ok(home) = home_dir() else {
+        return TagTheme::default();
+    };
+    let path = tag_theme_config_path(&home);
+    if !path.is_file() {
+        return TagTheme::default();
+    }
+    let Ok(file) = Figment::from(Toml::file(&path)).extract::<TagThemeFile>() else {
+        return TagTheme::default();
+    };
+
+    let palette = file
+        .palette
+        .as_deref()
+        .and_then(TagPalette::by_name)
+        .unwrap_or(TagPalette::Vivid);
+    let mut overrides: Vec<(String, Color)> = file
+        .colors
+        .into_iter()
+        .filter_map(|(pattern, raw)| parse_hex_color(&raw).map(|color| (pattern, color)))
+        .collect();
+    overrides.sort_by(|a, b| a.0.cmp(&b.0));
+
+    TagTheme { overrides, palette }
+}
+
+fn tag_pattern_matches(pattern: &str, tag: &str) -> bool {
+    if !pattern.contains('*') {
+        return pattern.eq_ignore_ascii_case(tag);
+    }
+    let escaped = regex::escape(pattern).replace("\\*", ".*");
+    Regex::new(&format!("(?i)^{}$", escaped))
+        .map(|re| re.is_match(tag))
+        .unwrap_or(false)
+}
+
+fn resolve_tag_override(theme: &TagTheme, tag: &str) -> Option<Color> {
+    theme
+        .overrides
+        .iter()
+        .find(|(pattern, _)| pattern.eq_ignore_ascii_case(tag))
+        .or_else(|| {
+            theme
+                .overrides
+                .iter()
+                .find(|(pattern, _)| tag_pattern_matches(pattern, tag))
+        })
+        .map(|(_, color)| *color)
+}
+
+fn color_luminance(color: Color) -> f32 {
+    match color {
+        Color::Rgb(r, g, b) => {
+            0.2126 * (r as f32 / 255.0) + 0.7152 * (g as f32 / 255.0) + 0.0722 * (b as f32 / 255.0)
+        }
+        _ => 0.5,
+    }
+}
+
+fn contrast_ratio(a: Color, b: Color) -> f32 {
+    let la = color_luminance(a) + 0.05;
+    let lb = color_luminance(b) + 0.05;
+    if la > lb {
+        la / lb
+    } else {
+        lb / la
+    }
+}
+
+const MIN_TAG_CONTRAST: f32 = 1.6;
+
+fn ensure_min_contrast(hue: f32, sat: f32, light: f32, base: Color, fallback: Color) -> Color {
+    if contrast_ratio(base, fallback) >= MIN_TAG_CONTRAST {
+        return base;
+    }
+    let direction: f32 = if color_luminance(fallback) > 0.5 {
+        -1.0
+    } else {
+        1.0
+    };
+    for step in 1..=4 {
+        let adjusted_light = (light + direction * 0.12 * step as f32).clamp(0.1, 0.9);
+        if let Some(candidate) = hsl_to_rgb(hue, sat, adjusted_light) {
+            if contrast_ratio(candidate, fallback) >= MIN_TAG_CONTRAST {
+                return candidate;
+            }
+        }
+    }
+    base
+}
+
+const FRECENCY_MAX_VISITS: usize = 10;
+
+#[derive(Default, Deserialize, Serialize)]
+struct FrecencyStore {
+    #[serde(default)]
+    visits: HashMap<String, Vec<u64>>,
+}
+
+fn frecency_store_path() -> Option<PathBuf> {
+    let home = home_dir().ok()?;
+    let data_dir = env::var("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| home.join(".local/share"));
+    Some(data_dir.join("navgator/frecency.toml"))
+}
+
+fn load_frecency_cache() -> HashMap<String, Vec<u64>> {
+    let Some(path) = frecency_store_path() else {
+        return HashMap::new();
+    };
+    if !path.is_file() {
+        return HashMap::new();
+    }
+    Figment::from(Toml::file(&path))
+        .extract::<FrecencyStore>()
+        .map(|store| store.visits)
+        .unwrap_or_default()
+}
+
+fn record_visit(path: &str) {
+    let Some(store_path) = frecency_store_path() else {
+        return;
+    };
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    let mut store: FrecencyStore = if store_path.is_file() {
+        Figment::from(Toml::file(&store_path))
+            .extract()
+            .unwrap_or_default()
+    } else {
+        FrecencyStore::default()
+    };
+
+    let entry = store.visits.entry(path.to_string()).or_default();
+    entry.push(now);
+    if entry.len() > FRECENCY_MAX_VISITS {
+        let overflow = entry.len() - FRECENCY_MAX_VISITS;
+        entry.drain(0..overflow);
+    }
+
+    if let Some(parent) = store_path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    if let Ok(serialized) = toml::to_string(&store) {
+        let _ = fs::write(&store_path, serialized);
+    }
+}
+
+fn frecency_score(visits: &[u64], now: u64) -> f64 {
+    if visits.is_empty() {
+        return 0.0;
+    }
+    const HOUR: u64 = 3600;
+    let weighted: f64 = visits
+        .iter()
+        .map(|&visit| {
+            let age = now.saturating_sub(visit);
+            if age <= 4 * HOUR {
+                100.0
+            } else if age <= 24 * HOUR {
+                80.0
+            } else if age <= 7 * 24 * HOUR {
+                60.0
+            } else if age <= 30 * 24 * HOUR {
+                30.0
+            } else {
+                10.0
+            }
+        })
+        .sum();
+    weighted * visits.len() as f64
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -95,6 +593,7 @@ enum SortMode {
     CreatedDesc,
     ModifiedAsc,
     ModifiedDesc,
+    Frecency,
 }
 
 impl SortMode {
@@ -106,7 +605,8 @@ impl SortMode {
             SortMode::CreatedAsc => SortMode::CreatedDesc,
             SortMode::CreatedDesc => SortMode::ModifiedAsc,
             SortMode::ModifiedAsc => SortMode::ModifiedDesc,
-            SortMode::ModifiedDesc => SortMode::Match,
+            SortMode::ModifiedDesc => SortMode::Frecency,
+            SortMode::Frecency => SortMode::Match,
         }
     }
 
@@ -119,6 +619,7 @@ impl SortMode {
             SortMode::CreatedDesc => "Created v",
             SortMode::ModifiedAsc => "Modified ^",
             SortMode::ModifiedDesc => "Modified v",
+            SortMode::Frecency => "Frecency",
         }
     }
 
@@ -139,6 +640,320 @@ enum Focus {
     Preview,
     Git,
     TagEdit,
+    Filesystems,
+}
+
+#[derive(Clone)]
+struct MountInfo {
+    mount_point: String,
+    device: String,
+    fs_type: String,
+    total_bytes: u64,
+    used_bytes: u64,
+    avail_bytes: u64,
+}
+
+const PSEUDO_FS_TYPES: &[&str] = &[
+    "proc",
+    "sysfs",
+    "tmpfs",
+    "devtmpfs",
+    "devpts",
+    "cgroup",
+    "cgroup2",
+    "overlay",
+    "squashfs",
+    "autofs",
+    "mqueue",
+    "debugfs",
+    "tracefs",
+    "securityfs",
+    "pstore",
+    "bpf",
+    "configfs",
+    "fusectl",
+    "hugetlbfs",
+    "binfmt_misc",
+    "rpc_pipefs",
+    "nsfs",
+];
+
+fn read_mounts() -> Vec<MountInfo> {
+    #[cfg(target_os = "linux")]
+    {
+        read_mounts_linux()
+    }
+    #[cfg(target_os = "macos")]
+    {
+        read_mounts_macos()
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+    {
+        Vec::new()
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn read_mounts_linux() -> Vec<MountInfo> {
+    let contents = match fs::read_to_string("/proc/mounts") {
+        Ok(value) => value,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut mounts = Vec::new();
+    for line in contents.lines() {
+        let mut fields = line.split_whitespace();
+        let device = match fields.next() {
+            Some(value) => value.to_string(),
+            None => continue,
+        };
+        let mount_point = match fields.next() {
+            Some(value) => value.to_string(),
+            None => continue,
+        };
+        let fs_type = match fields.next() {
+            Some(value) => value.to_string(),
+            None => continue,
+        };
+        let options = fields.next().unwrap_or("");
+        if PSEUDO_FS_TYPES.contains(&fs_type.as_str()) {
+            continue;
+        }
+        if options.split(',').any(|opt| opt == "bind") {
+            continue;
+        }
+        if let Some((total, used, avail)) = statvfs_usage(&mount_point) {
+            mounts.push(MountInfo {
+                mount_point,
+                device,
+                fs_type,
+                total_bytes: total,
+                used_bytes: used,
+                avail_bytes: avail,
+            });
+        }
+    }
+    mounts
+}
+
+#[cfg(target_os = "linux")]
+fn statvfs_usage(path: &str) -> Option<(u64, u64, u64)> {
+    use std::ffi::CString;
+    use std::mem::MaybeUninit;
+
+    let cpath = CString::new(path).ok()?;
+    unsafe {
+        let mut stat: MaybeUninit<libc::statvfs> = MaybeUninit::uninit();
+        if libc::statvfs(cpath.as_ptr(), stat.as_mut_ptr()) != 0 {
+            return None;
+        }
+        let stat = stat.assume_init();
+        let block_size = stat.f_frsize.max(1) as u64;
+        let total = stat.f_blocks as u64 * block_size;
+        let free = stat.f_bfree as u64 * block_size;
+        let avail = stat.f_bavail as u64 * block_size;
+        let used = total.saturating_sub(free);
+        Some((total, used, avail))
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn read_mounts_macos() -> Vec<MountInfo> {
+    unsafe {
+        let mut buf_ptr: *mut libc::statfs = std::ptr::null_mut();
+        let count = libc::getmntinfo(&mut buf_ptr, libc::MNT_NOWAIT);
+        if count <= 0 || buf_ptr.is_null() {
+            return Vec::new();
+        }
+        let entries = std::slice::from_raw_parts(buf_ptr, count as usize);
+        entries
+            .iter()
+            .filter_map(|entry| {
+                let fs_type = c_chars_to_string(&entry.f_fstypename);
+                if PSEUDO_FS_TYPES.contains(&fs_type.as_str()) {
+                    return None;
+                }
+                let mount_point = c_chars_to_string(&entry.f_mntonname);
+                let device = c_chars_to_string(&entry.f_mntfromname);
+                let block_size = entry.f_bsize.max(1) as u64;
+                let total = entry.f_blocks as u64 * block_size;
+                let free = entry.f_bfree as u64 * block_size;
+                let avail = entry.f_bavail as u64 * block_size;
+                let used = total.saturating_sub(free);
+                Some(MountInfo {
+                    mount_point,
+                    device,
+                    fs_type,
+                    total_bytes: total,
+                    used_bytes: used,
+                    avail_bytes: avail,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn c_chars_to_string(chars: &[libc::c_char]) -> String {
+    let bytes: Vec<u8> = chars
+        .iter()
+        .take_while(|&&c| c != 0)
+        .map(|&c| c as u8)
+        .collect();
+    String::from_utf8_lossy(&bytes).to_string()
+}
+
+fn render_filesystems_table(
+    frame: &mut ratatui::Frame,
+    header_area: Rect,
+    body_area: Rect,
+    mounts: &[MountInfo],
+    selected: usize,
+    text: Color,
+    muted: Color,
+    accent: Color,
+) {
+    let header = Paragraph::new(Span::styled(
+        format!("{} mounted filesystems", mounts.len()),
+        Style::default().fg(accent).add_modifier(Modifier::BOLD),
+    ));
+    frame.render_widget(header, header_area);
+
+    if mounts.is_empty() {
+        let empty = Paragraph::new(Span::styled(
+            "No mounted filesystems found",
+            Style::default().fg(muted),
+        ));
+        frame.render_widget(empty, body_area);
+        return;
+    }
+
+    let rows: Vec<Row> = mounts
+        .iter()
+        .map(|mount| {
+            let ratio = if mount.total_bytes == 0 {
+                0.0
+            } else {
+                mount.used_bytes as f64 / mount.total_bytes as f64
+            };
+            let bar = Line::from(usage_bar_spans(ratio, 12));
+            Row::new(vec![
+                Cell::from(Span::styled(
+                    mount.mount_point.clone(),
+                    Style::default().fg(text),
+                )),
+                Cell::from(Span::styled(mount.device.clone(), Style::default().fg(muted))),
+                Cell::from(Span::styled(mount.fs_type.clone(), Style::default().fg(muted))),
+                Cell::from(Span::styled(
+                    format_bytes(mount.used_bytes),
+                    Style::default().fg(text),
+                )),
+                Cell::from(Span::styled(
+                    format_bytes(mount.total_bytes),
+                    Style::default().fg(text),
+                )),
+                Cell::from(Span::styled(
+                    format_bytes(mount.avail_bytes),
+                    Style::default().fg(muted),
+                )),
+                Cell::from(bar),
+            ])
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Percentage(30),
+        Constraint::Percentage(18),
+        Constraint::Percentage(10),
+        Constraint::Length(8),
+        Constraint::Length(8),
+        Constraint::Length(8),
+        Constraint::Length(14),
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(
+            Row::new(vec!["Mount", "Device", "Type", "Used", "Total", "Avail", "Usage"])
+                .style(Style::default().fg(accent).add_modifier(Modifier::BOLD)),
+        )
+        .highlight_style(
+            Style::default()
+                .fg(Color::Black)
+                .bg(accent)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    let mut state = TableState::default();
+    state.select(Some(selected.min(mounts.len().saturating_sub(1))));
+    frame.render_stateful_widget(table, body_area, &mut state);
+}
+
+fn render_theme_picker(
+    frame: &mut ratatui::Frame,
+    header_area: Rect,
+    body_area: Rect,
+    themes: &[(&'static str, Theme)],
+    selected: usize,
+    theme: &Theme,
+) {
+    let header = Paragraph::new(Span::styled(
+        "Pick a theme  Enter apply  Esc cancel",
+        Style::default().fg(theme.accent).add_modifier(Modifier::BOLD),
+    ));
+    frame.render_widget(header, header_area);
+
+    let items: Vec<ListItem> = themes
+        .iter()
+        .map(|(name, candidate)| {
+            ListItem::new(Line::from(Span::styled(
+                name.to_string(),
+                Style::default().fg(candidate.accent),
+            )))
+        })
+        .collect();
+
+    let list = List::new(items).highlight_style(
+        Style::default()
+            .fg(Color::Black)
+            .bg(theme.selection)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let mut state = ListState::default();
+    state.select(Some(selected.min(themes.len().saturating_sub(1))));
+    frame.render_stateful_widget(list, body_area, &mut state);
+}
+
+fn usage_bar_spans(ratio: f64, width: usize) -> Vec<Span<'static>> {
+    let filled = (ratio.clamp(0.0, 1.0) * width as f64).round() as usize;
+    let color = if ratio < 0.7 {
+        Color::Rgb(100, 200, 120)
+    } else if ratio < 0.9 {
+        Color::Rgb(240, 180, 70)
+    } else {
+        Color::Rgb(220, 80, 80)
+    };
+    let filled_bar = "\u{2588}".repeat(filled.min(width));
+    let empty_bar = "\u{2591}".repeat(width.saturating_sub(filled.min(width)));
+    vec![
+        Span::styled(filled_bar, Style::default().fg(color)),
+        Span::styled(empty_bar, Style::default().fg(Color::Rgb(90, 90, 90))),
+    ]
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "K", "M", "G", "T"];
+    let mut value = bytes as f64;
+    let mut unit = 0usize;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{}{}", bytes, UNITS[unit])
+    } else {
+        format!("{:.1}{}", value, UNITS[unit])
+    }
 }
 
 fn main() -> AppResult<()> {
@@ -147,6 +962,9 @@ fn main() -> AppResult<()> {
     if args.is_empty() || args[0] == "navigate" {
         return run_navigate();
     }
+    if args[0] == "pick" {
+        return run_pick(&args[1..]);
+    }
     if args[0] == "--help" || args[0] == "-h" {
         print_usage();
         return Ok(());
@@ -177,17 +995,77 @@ fn ensure_tty_stdin() -> AppResult<()> {
 }
 
 fn print_usage() {
-    eprintln!("Usage:\n  navgator [navigate]");
+    eprintln!(
+        "Usage:\n  navgator [navigate]\n  navgator pick [--reply <path>] [--query <text>] [--root <dir>]"
+    );
 }
 
 fn run_navigate() -> AppResult<()> {
     let items = build_items()?;
-    match select_from_list("Navigate", &items)? {
+    match select_from_list("Navigate", &items, "")? {
         Some(choice) => write_selection(&choice),
         None => std::process::exit(1),
     }
 }
 
+const PICK_CANCEL_SENTINEL: &str = "__navgator_cancelled__";
+
+fn run_pick(args: &[String]) -> AppResult<()> {
+    let mut reply_path: Option<String> = None;
+    let mut query = String::new();
+    let mut root: Option<String> = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--reply" => reply_path = iter.next().cloned(),
+            "--query" => query = iter.next().cloned().unwrap_or_default(),
+            "--root" => root = iter.next().cloned(),
+            _ => {}
+        }
+    }
+
+    let items = match &root {
+        Some(root) => build_items_with_root(root)?,
+        None => build_items()?,
+    };
+
+    match select_from_list("Navigate", &items, &query)? {
+        Some(choice) => {
+            if let Some(path) = &reply_path {
+                reply_to_path(path, &choice)?;
+                Ok(())
+            } else {
+                write_selection(&choice)
+            }
+        }
+        None => {
+            if let Some(path) = &reply_path {
+                reply_to_path(path, PICK_CANCEL_SENTINEL)?;
+            }
+            std::process::exit(1);
+        }
+    }
+}
+
+fn reply_to_path(path: &str, payload: &str) -> AppResult<()> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::FileTypeExt;
+        use std::os::unix::net::UnixStream;
+        if fs::metadata(path)
+            .map(|meta| meta.file_type().is_socket())
+            .unwrap_or(false)
+        {
+            let mut stream = UnixStream::connect(path)?;
+            stream.write_all(payload.as_bytes())?;
+            return Ok(());
+        }
+    }
+    fs::write(path, payload)?;
+    Ok(())
+}
+
 fn write_selection(path: &str) -> AppResult<()> {
     if let Ok(output_path) = env::var("NAVGATOR_OUTPUT") {
         if !output_path.is_empty() {
@@ -201,8 +1079,19 @@ fn write_selection(path: &str) -> AppResult<()> {
 
 fn build_items() -> AppResult<Vec<String>> {
     let config = load_config()?;
-    let mut items: Vec<PathBuf> = config.static_items;
-    let index_folders = config.index_folders;
+    build_items_from(config.static_items, config.index_folders)
+}
+
+fn build_items_with_root(root: &str) -> AppResult<Vec<String>> {
+    let config = load_config()?;
+    build_items_from(config.static_items, vec![PathBuf::from(root)])
+}
+
+fn build_items_from(
+    static_items: Vec<PathBuf>,
+    index_folders: Vec<PathBuf>,
+) -> AppResult<Vec<String>> {
+    let mut items: Vec<PathBuf> = static_items;
 
     for folder in index_folders {
         items.push(folder.clone());
@@ -242,6 +1131,7 @@ fn load_config() -> AppResult<LoadedConfig> {
     let mut seen_index = HashSet::new();
     let mut seen_static = HashSet::new();
     let mut found_config = false;
+    let mut preview = PreviewConfig::default();
 
     for path in config_paths(&home) {
         if !path.is_file() {
@@ -268,6 +1158,17 @@ fn load_config() -> AppResult<LoadedConfig> {
                 &mut seen_static,
             );
         }
+        if let Some(preview_config) = config.preview {
+            if let Some(highlight) = preview_config.highlight {
+                preview.highlight_enabled = highlight;
+            }
+            if let Some(theme) = preview_config.theme {
+                preview.theme_name = theme;
+            }
+            if let Some(overlay) = preview_config.git_status_overlay {
+                preview.git_status_overlay = overlay;
+            }
+        }
     }
 
     if !found_config {
@@ -277,6 +1178,7 @@ fn load_config() -> AppResult<LoadedConfig> {
     Ok(LoadedConfig {
         index_folders,
         static_items,
+        preview,
     })
 }
 
@@ -350,29 +1252,49 @@ fn normalize_path(raw: &str, base_dir: &Path, home: &Path) -> Option<PathBuf> {
     }
 }
 
+fn path_modified(path: &str) -> Option<SystemTime> {
+    fs::metadata(path).ok()?.modified().ok()
+}
+
 fn is_dir(path: &Path) -> bool {
     fs::metadata(path)
         .map(|meta| meta.is_dir())
         .unwrap_or(false)
 }
 
-fn select_from_list(_title: &str, items: &[String]) -> AppResult<Option<String>> {
+fn select_from_list(
+    _title: &str,
+    items: &[String],
+    initial_query: &str,
+) -> AppResult<Option<String>> {
     if items.is_empty() {
         return Ok(None);
     }
+    let mut items: Vec<String> = items.to_vec();
 
     let (mut terminal, _guard) = setup_terminal()?;
-    let mut input = Input::default();
+    let preview_config = load_config().map(|c| c.preview).unwrap_or_default();
+    let mut input = if initial_query.is_empty() {
+        Input::default()
+    } else {
+        Input::new(initial_query.to_string())
+    };
     let mut selected = 0usize;
     let mut sort_mode = SortMode::Match;
     let mut focus = Focus::Search;
     let mut meta_cache: HashMap<String, SortMeta> = HashMap::new();
     let mut list_offset = 0usize;
-    let accent = Color::Rgb(72, 166, 255);
-    let warm = Color::Rgb(255, 181, 92);
-    let key_color = Color::Rgb(150, 150, 150);
-    let text = Color::Black;
-    let muted = text;
+    let themes = Theme::builtins();
+    let mut theme = load_theme();
+    let tag_theme = load_tag_theme();
+    let mut theme_picker_active = false;
+    let mut theme_picker_selected = 0usize;
+    let mut theme_before_picker: Option<Theme> = None;
+    let mut accent = theme.accent;
+    let mut warm = theme.selection;
+    let mut key_color = theme.key;
+    let mut text = theme.text;
+    let mut muted = text;
     let (preview_tx, preview_rx) = mpsc::channel::<PreviewResult>();
     let (date_tx, date_rx) = mpsc::channel::<MetaResult>();
     let (tag_tx, tag_rx) = mpsc::channel::<TagResult>();
@@ -382,11 +1304,22 @@ fn select_from_list(_title: &str, items: &[String]) -> AppResult<Option<String>>
     let mut tag_cache: HashMap<String, Vec<String>> = HashMap::new();
     let mut tag_in_flight: HashSet<String> = HashSet::new();
     let mut tag_scan_started = false;
-    let mut filtered = filter_and_sort(items, input.value(), sort_mode, &meta_cache, &tag_cache);
+    let frecency_cache = load_frecency_cache();
+    let mut filtered = filter_and_sort(
+        &items,
+        input.value(),
+        sort_mode,
+        &meta_cache,
+        &tag_cache,
+        &frecency_cache,
+    );
     let mut preview_path: Option<String> = None;
     let mut in_flight: Option<String> = None;
     let mut preview_text = build_placeholder_text(None, accent, muted, text, "No selection");
     let mut git_text: Option<Text<'static>> = None;
+    let mut git_commit_rows: Vec<(usize, String)> = Vec::new();
+    let mut git_commit_expanded: HashSet<String> = HashSet::new();
+    let mut metadata_footer: Option<Line<'static>> = None;
     let mut preview_scroll = 0usize;
     let mut git_scroll = 0usize;
     let mut preview_max_scroll = 0usize;
@@ -394,13 +1327,35 @@ fn select_from_list(_title: &str, items: &[String]) -> AppResult<Option<String>>
     let mut preview_page_step = 5usize;
     let mut git_page_step = 5usize;
     let start_time = Instant::now();
+    let mut displayed_image: Option<String> = None;
+    let mut last_preview_area = Rect::default();
+    let mut mounts: Vec<MountInfo> = Vec::new();
+    let mut fs_selected = 0usize;
     let mut tag_edit_path: Option<String> = None;
     let mut tag_edit_tags: Vec<String> = Vec::new();
     let mut tag_input = Input::default();
     let mut tag_suggestions: Vec<String> = Vec::new();
+    let (content_tx, content_rx) = mpsc::channel::<ContentResult>();
+    let content_roots: Vec<String> = items.to_vec();
+    let mut content_mode = false;
+    let mut content_hits: Vec<ContentHit> = Vec::new();
+    let mut content_selected = 0usize;
+    let mut content_offset = 0usize;
+    let mut content_generation = 0u64;
+    let mut content_dirty_since: Option<Instant> = None;
 
     loop {
-        let current = current_selection_path(items, &filtered, selected);
+        accent = theme.accent;
+        warm = theme.selection;
+        key_color = theme.key;
+        text = theme.text;
+        muted = text;
+
+        let current = if content_mode {
+            content_hits.get(content_selected).map(|hit| hit.path.clone())
+        } else {
+            current_selection_path(&items, &filtered, selected)
+        };
         let query_value = input.value();
         let tokens = parse_query_tokens(query_value);
 
@@ -409,6 +1364,7 @@ fn select_from_list(_title: &str, items: &[String]) -> AppResult<Option<String>>
             if current.as_deref() == Some(result.path.as_str()) {
                 preview_text = result.data.preview;
                 git_text = result.data.git;
+                git_commit_rows = result.data.git_commit_rows;
                 preview_path = Some(result.path.clone());
             }
             if in_flight.as_deref() == Some(result.path.as_str()) {
@@ -442,26 +1398,62 @@ fn select_from_list(_title: &str, items: &[String]) -> AppResult<Option<String>>
             tags_changed = true;
         }
 
+        while let Ok(result) = content_rx.try_recv() {
+            if result.generation == content_generation {
+                content_hits.push(result.hit);
+            }
+        }
+
+        if let Some(dirty_at) = content_dirty_since {
+            if dirty_at.elapsed() >= CONTENT_DEBOUNCE {
+                content_dirty_since = None;
+                content_hits.clear();
+                content_selected = 0;
+                content_offset = 0;
+                content_generation += 1;
+                spawn_content_search(
+                    content_roots.clone(),
+                    input.value().to_string(),
+                    content_generation,
+                    content_tx.clone(),
+                );
+            }
+        }
+
         let query_uses_tags = tokens.needs_tags();
         if query_uses_tags && !tag_scan_started {
-            spawn_bulk_tag_fetch(items, &tag_cache, &mut tag_in_flight, &tag_tx);
+            spawn_bulk_tag_fetch(&items, &tag_cache, &mut tag_in_flight, &tag_tx);
             tag_scan_started = true;
         }
 
         if resort_needed {
-            let selected_path = current_selection_path(items, &filtered, selected);
-            filtered = filter_and_sort(items, input.value(), sort_mode, &meta_cache, &tag_cache);
+            let selected_path = current_selection_path(&items, &filtered, selected);
+            filtered = filter_and_sort(
+                &items,
+                input.value(),
+                sort_mode,
+                &meta_cache,
+                &tag_cache,
+                &frecency_cache,
+            );
             selected = match selected_path {
-                Some(path) => index_for_path(items, &filtered, &path).unwrap_or(0),
+                Some(path) => index_for_path(&items, &filtered, &path).unwrap_or(0),
                 None => adjust_selected_index(selected, filtered.len()),
             };
         }
 
         if tags_changed && query_uses_tags {
-            let selected_path = current_selection_path(items, &filtered, selected);
-            filtered = filter_and_sort(items, input.value(), sort_mode, &meta_cache, &tag_cache);
+            let selected_path = current_selection_path(&items, &filtered, selected);
+            filtered = filter_and_sort(
+                &items,
+                input.value(),
+                sort_mode,
+                &meta_cache,
+                &tag_cache,
+                &frecency_cache,
+            );
             selected = match selected_path {
-                Some(path) => index_for_path(items, &filtered, &path).unwrap_or(0),
+                Some(path) => index_for_path(&items, &filtered, &path).unwrap_or(0),
                 None => adjust_selected_index(selected, filtered.len()),
             };
         }
@@ -472,6 +1464,8 @@ fn select_from_list(_title: &str, items: &[String]) -> AppResult<Option<String>>
                     preview_text =
                         build_placeholder_text(None, accent, muted, text, "No selection");
                     git_text = None;
+                    git_commit_rows = Vec::new();
+                    metadata_footer = None;
                     preview_path = None;
                     in_flight = None;
                     preview_scroll = 0;
@@ -482,9 +1476,15 @@ fn select_from_list(_title: &str, items: &[String]) -> AppResult<Option<String>>
                 if preview_path.as_deref() != Some(path) {
                     preview_scroll = 0;
                     git_scroll = 0;
-                    if let Some(data) = preview_cache.get(path) {
+                    metadata_footer = build_metadata_footer(path, muted);
+                    let current_mtime = path_modified(path);
+                    let fresh_cached = preview_cache
+                        .get(path)
+                        .filter(|data| data.modified == current_mtime);
+                    if let Some(data) = fresh_cached {
                         preview_text = data.preview.clone();
                         git_text = data.git.clone();
+                        git_commit_rows = data.git_commit_rows.clone();
                         preview_path = Some(path.to_string());
                     } else if in_flight.as_deref() != Some(path) {
                         preview_text = build_placeholder_text(
@@ -505,12 +1505,44 @@ fn select_from_list(_title: &str, items: &[String]) -> AppResult<Option<String>>
                         in_flight = Some(path.to_string());
                         let tx = preview_tx.clone();
                         let path_owned = path.to_string();
+                        let preview_config = preview_config.clone();
+                        let (image_cols, image_rows) = image_preview_cell_dims(last_preview_area);
+                        let expanded_commits = git_commit_expanded.clone();
                         thread::spawn(move || {
-                            let preview = build_preview_text(&path_owned, accent, muted, text);
-                            let git = build_git_text(&path_owned, accent, muted, text);
+                            let image = if is_image_path(Path::new(&path_owned)) {
+                                build_image_preview(Path::new(&path_owned), image_cols, image_rows)
+                            } else {
+                                None
+                            };
+                            let preview = match &image {
+                                Some(image) => {
+                                    build_image_preview_text(&path_owned, image, text)
+                                }
+                                None => build_preview_text(
+                                    &path_owned,
+                                    accent,
+                                    muted,
+                                    text,
+                                    &preview_config,
+                                ),
+                            };
+                            let (git, git_commit_rows) = match build_git_text(
+                                &path_owned,
+                                &theme,
+                                &expanded_commits,
+                            ) {
+                                Some((git, rows)) => (Some(git), rows),
+                                None => (None, Vec::new()),
+                            };
                             let _ = tx.send(PreviewResult {
                                 path: path_owned,
-                                data: PreviewData { preview, git },
+                                data: PreviewData {
+                                    preview,
+                                    git,
+                                    git_commit_rows,
+                                    image,
+                                    modified: current_mtime,
+                                },
                             });
                         });
                     }
@@ -518,6 +1550,12 @@ fn select_from_list(_title: &str, items: &[String]) -> AppResult<Option<String>>
             }
         }
 
+        if content_mode {
+            if let Some(hit) = content_hits.get(content_selected) {
+                preview_scroll = hit.line.saturating_sub(1);
+            }
+        }
+
         if focus == Focus::Git && git_text.is_none() {
             focus = Focus::Preview;
         }
@@ -527,19 +1565,34 @@ fn select_from_list(_title: &str, items: &[String]) -> AppResult<Option<String>>
 
         let show_git = git_text.is_some();
         let size = terminal.size()?;
-        let ui = compute_ui_layout(size.into(), show_git);
+        let ui = compute_ui_layout(size.into(), show_git, metadata_footer.is_some());
+        last_preview_area = ui.preview_area;
 
         terminal.draw(|frame| {
+            frame.render_widget(
+                Block::default().style(Style::default().bg(theme.background)),
+                size.into(),
+            );
+
             let list_area = ui.list_area;
             let detail_area = ui.detail_area;
 
-            let list_title = format!("Results {}/{}", filtered.len(), items.len());
-            let left_title = if focus == Focus::Search {
+            let left_focused = matches!(focus, Focus::Search | Focus::Filesystems);
+            let list_title = if theme_picker_active {
+                "Themes".to_string()
+            } else if focus == Focus::Filesystems {
+                format!("Filesystems {}", mounts.len())
+            } else if content_mode {
+                format!("Content hits {}", content_hits.len())
+            } else {
+                format!("Results {}/{}", filtered.len(), items.len())
+            };
+            let left_title = if left_focused {
                 format!("* {}", list_title)
             } else {
                 list_title
             };
-            let left_border_style = if focus == Focus::Search {
+            let left_border_style = if left_focused {
                 Style::default().fg(accent)
             } else {
                 Style::default().fg(muted)
@@ -554,59 +1607,106 @@ fn select_from_list(_title: &str, items: &[String]) -> AppResult<Option<String>>
             let search_area = ui.search_area;
             let results_area = ui.results_area;
 
-            let search_width = search_area.width.saturating_sub(1) as usize;
-            let scroll = if search_width > 0 {
-                input.visual_scroll(search_width)
+            if theme_picker_active {
+                render_theme_picker(frame, search_area, results_area, &themes, theme_picker_selected, &theme);
+            } else if focus == Focus::Filesystems {
+                render_filesystems_table(
+                    frame,
+                    search_area,
+                    results_area,
+                    &mounts,
+                    fs_selected,
+                    text,
+                    muted,
+                    accent,
+                );
             } else {
-                0
-            };
-            let search = Paragraph::new(input.value())
-                .scroll((0, scroll as u16))
-                .alignment(Alignment::Left)
-                .wrap(Wrap { trim: false });
-            frame.render_widget(search, search_area);
-            if focus == Focus::Search && search_area.width > 0 && search_area.height > 0 {
-                let cursor_x = input.visual_cursor().max(scroll).saturating_sub(scroll);
-                frame.set_cursor_position((search_area.x + cursor_x as u16, search_area.y));
-            }
-
-            let list_inner_height = results_area.height as usize;
-            let total = filtered.len();
-            list_offset =
-                compute_list_window_offset(selected, list_offset, list_inner_height, total);
-
-            let scrollbar_space = if total > 0 { 1 } else { 0 };
-            let list_inner_width = results_area.width.saturating_sub(scrollbar_space) as usize;
-            let visible_paths =
-                visible_paths_for_window(items, &filtered, list_offset, list_inner_height);
-            ensure_dates_for_paths(&visible_paths, &date_cache, &mut date_in_flight, &date_tx);
-            ensure_tags_for_paths(&visible_paths, &tag_cache, &mut tag_in_flight, &tag_tx);
-
-            let (list_items, list_selected) = build_visible_list_items(
-                items,
-                &filtered,
-                selected,
-                list_offset,
-                list_inner_height,
-                text,
-                muted,
-                &date_cache,
-                &tag_cache,
-                list_inner_width,
-                &tokens,
-                start_time.elapsed().as_millis() as u64,
-            );
-
-            let list = List::new(list_items).highlight_style(
-                Style::default()
-                    .fg(Color::Black)
-                    .bg(warm)
-                    .add_modifier(Modifier::BOLD),
-            );
+                let search_width = search_area.width.saturating_sub(1) as usize;
+                let scroll = if search_width > 0 {
+                    input.visual_scroll(search_width)
+                } else {
+                    0
+                };
+                let search = Paragraph::new(input.value())
+                    .scroll((0, scroll as u16))
+                    .alignment(Alignment::Left)
+                    .wrap(Wrap { trim: false });
+                frame.render_widget(search, search_area);
+                if focus == Focus::Search && search_area.width > 0 && search_area.height > 0 {
+                    let cursor_x = input.visual_cursor().max(scroll).saturating_sub(scroll);
+                    frame.set_cursor_position((search_area.x + cursor_x as u16, search_area.y));
+                }
 
-            let mut state = ListState::default();
-            state.select(list_selected);
-            frame.render_stateful_widget(list, results_area, &mut state);
+                let list_inner_height = results_area.height as usize;
+
+                let (list_items, list_selected) = if content_mode {
+                    let total = content_hits.len();
+                    content_offset = compute_list_window_offset(
+                        content_selected,
+                        content_offset,
+                        list_inner_height,
+                        total,
+                    );
+                    build_content_hit_items(
+                        &content_hits,
+                        content_selected,
+                        content_offset,
+                        list_inner_height,
+                        text,
+                        muted,
+                    )
+                } else {
+                    let total = filtered.len();
+                    list_offset = compute_list_window_offset(
+                        selected,
+                        list_offset,
+                        list_inner_height,
+                        total,
+                    );
+
+                    let scrollbar_space = if total > 0 { 1 } else { 0 };
+                    let list_inner_width =
+                        results_area.width.saturating_sub(scrollbar_space) as usize;
+                    let visible_paths =
+                        visible_paths_for_window(&items, &filtered, list_offset, list_inner_height);
+                    ensure_dates_for_paths(
+                        &visible_paths,
+                        &date_cache,
+                        &mut date_in_flight,
+                        &date_tx,
+                    );
+                    ensure_tags_for_paths(&visible_paths, &tag_cache, &mut tag_in_flight, &tag_tx);
+
+                    build_visible_list_items(
+                        &items,
+                        &filtered,
+                        selected,
+                        list_offset,
+                        list_inner_height,
+                        text,
+                        muted,
+                        theme.match_highlight,
+                        theme.tag,
+                        &date_cache,
+                        &tag_cache,
+                        list_inner_width,
+                        &tokens,
+                        start_time.elapsed().as_millis() as u64,
+                        &tag_theme,
+                    )
+                };
+
+                let list = List::new(list_items).highlight_style(
+                    Style::default()
+                        .fg(Color::Black)
+                        .bg(warm)
+                        .add_modifier(Modifier::BOLD),
+                );
+
+                let mut state = ListState::default();
+                state.select(list_selected);
+                frame.render_stateful_widget(list, results_area, &mut state);
+            }
 
             let preview_height = ui.preview_area.height.saturating_sub(2) as usize;
             let git_height = ui
@@ -636,10 +1736,13 @@ fn select_from_list(_title: &str, items: &[String]) -> AppResult<Option<String>>
                     &tag_input,
                     preview_width,
                     text,
+                    theme.tag,
+                    &tag_suggestions,
+                    &tag_theme,
                 )
             } else {
                 (
-                    compose_preview_text(&preview_text, &preview_tags, preview_width, text),
+                    compose_preview_text(&preview_text, &preview_tags, preview_width, theme.tag, &tag_theme),
                     None,
                 )
             };
@@ -666,10 +1769,10 @@ fn select_from_list(_title: &str, items: &[String]) -> AppResult<Option<String>>
                 git_text.as_ref(),
                 &preview_title,
                 focus,
-                accent,
-                text,
+                &theme,
                 preview_scroll as u16,
                 git_scroll as u16,
+                metadata_footer.as_ref(),
             );
             if focus == Focus::TagEdit {
                 if let Some((row, col)) = tag_cursor {
@@ -684,6 +1787,8 @@ fn select_from_list(_title: &str, items: &[String]) -> AppResult<Option<String>>
 
             let help_line = build_help_line(
                 focus,
+                content_mode,
+                theme_picker_active,
                 sort_mode,
                 show_git,
                 input_at_end(&input),
@@ -691,9 +1796,7 @@ fn select_from_list(_title: &str, items: &[String]) -> AppResult<Option<String>>
                 preview_scroll,
                 preview_max_scroll,
                 git_scroll,
-                text,
-                accent,
-                key_color,
+                &theme,
             );
             let help = Paragraph::new(Text::from(help_line))
                 .block(
@@ -708,10 +1811,49 @@ fn select_from_list(_title: &str, items: &[String]) -> AppResult<Option<String>>
             frame.render_widget(help, ui.help_area);
         })?;
 
+        let kitty_escape = current
+            .as_deref()
+            .and_then(|path| preview_cache.get(path))
+            .and_then(|data| data.image.as_ref())
+            .and_then(|image| image.kitty_escape.as_ref());
+        match kitty_escape {
+            Some(escape) if displayed_image.as_deref() != current.as_deref() => {
+                execute!(
+                    io::stderr(),
+                    MoveTo(ui.preview_area.x + 1, ui.preview_area.y + 1)
+                )?;
+                io::stderr().write_all(escape.as_bytes())?;
+                io::stderr().flush()?;
+                displayed_image = current.clone();
+            }
+            None if displayed_image.is_some() => {
+                execute!(io::stderr(), Clear(ClearType::All))?;
+                terminal.clear()?;
+                displayed_image = None;
+            }
+            _ => {}
+        }
+
         if event::poll(Duration::from_millis(100))? {
             match event::read()? {
                 Event::Key(key) => {
                     if key.code == KeyCode::Esc {
+                        if theme_picker_active {
+                            if let Some(previous) = theme_before_picker.take() {
+                                theme = previous;
+                            }
+                            theme_picker_active = false;
+                            continue;
+                        }
+                        if content_mode {
+                            content_mode = false;
+                            content_dirty_since = None;
+                            continue;
+                        }
+                        if focus == Focus::Filesystems {
+                            focus = Focus::Search;
+                            continue;
+                        }
                         terminal.show_cursor()?;
                         return Ok(None);
                     }
@@ -721,56 +1863,177 @@ fn select_from_list(_title: &str, items: &[String]) -> AppResult<Option<String>>
                         terminal.show_cursor()?;
                         return Ok(None);
                     }
+                    if key.code == KeyCode::Char('f')
+                        && key.modifiers.contains(KeyModifiers::CONTROL)
+                        && focus != Focus::Filesystems
+                        && !content_mode
+                    {
+                        mounts = read_mounts();
+                        fs_selected = 0;
+                        focus = Focus::Filesystems;
+                        continue;
+                    }
+                    if key.code == KeyCode::Char('g')
+                        && key.modifiers.contains(KeyModifiers::CONTROL)
+                        && focus == Focus::Search
+                    {
+                        content_mode = !content_mode;
+                        if content_mode {
+                            content_hits.clear();
+                            content_selected = 0;
+                            content_offset = 0;
+                            content_generation += 1;
+                            content_dirty_since = Some(Instant::now() - CONTENT_DEBOUNCE);
+                        } else {
+                            content_dirty_since = None;
+                        }
+                        continue;
+                    }
+                    if key.code == KeyCode::Char('p')
+                        && key.modifiers.contains(KeyModifiers::CONTROL)
+                        && focus == Focus::Search
+                        && !content_mode
+                    {
+                        theme_picker_active = !theme_picker_active;
+                        if theme_picker_active {
+                            theme_before_picker = Some(theme);
+                            theme_picker_selected = themes
+                                .iter()
+                                .position(|(_, candidate)| *candidate == theme)
+                                .unwrap_or(0);
+                            theme = themes[theme_picker_selected].1;
+                        } else if let Some(previous) = theme_before_picker.take() {
+                            theme = previous;
+                        }
+                        continue;
+                    }
+                    if theme_picker_active {
+                        match key.code {
+                            KeyCode::Up => {
+                                if theme_picker_selected > 0 {
+                                    theme_picker_selected -= 1;
+                                }
+                                theme = themes[theme_picker_selected].1;
+                            }
+                            KeyCode::Down => {
+                                if theme_picker_selected + 1 < themes.len() {
+                                    theme_picker_selected += 1;
+                                }
+                                theme = themes[theme_picker_selected].1;
+                            }
+                            KeyCode::Enter => {
+                                theme_before_picker = None;
+                                theme_picker_active = false;
+                            }
+                            _ => {}
+                        }
+                        continue;
+                    }
                     if key.code == KeyCode::Char('t')
                         && key.modifiers.contains(KeyModifiers::CONTROL)
                         && focus != Focus::TagEdit
+                        && focus != Focus::Filesystems
+                        && !content_mode
                     {
-                        if let Some(path) = current_selection_path(items, &filtered, selected) {
+                        if let Some(path) = current_selection_path(&items, &filtered, selected) {
                             tag_edit_path = Some(path.clone());
                             tag_edit_tags = read_tags_for_path(&path);
                             tag_input.reset();
-                            tag_suggestions = collect_tag_suggestions(&tag_cache);
+                            tag_suggestions =
+                                collect_tag_suggestions(&tag_cache, &path, &tag_edit_tags);
                             focus = Focus::TagEdit;
                             preview_scroll = 0;
                         }
                         continue;
                     }
-                    if key.code == KeyCode::Enter && focus != Focus::TagEdit {
+                    if key.code == KeyCode::Enter
+                        && focus != Focus::TagEdit
+                        && focus != Focus::Filesystems
+                        && content_mode
+                    {
+                        if let Some(hit) = content_hits.get(content_selected) {
+                            let value = if env::var("NAVGATOR_OUTPUT")
+                                .map(|value| !value.is_empty())
+                                .unwrap_or(false)
+                            {
+                                format!("{}:{}", hit.path, hit.line)
+                            } else {
+                                hit.path.clone()
+                            };
+                            record_visit(&hit.path);
+                            terminal.show_cursor()?;
+                            return Ok(Some(value));
+                        }
+                        continue;
+                    }
+                    if key.code == KeyCode::Enter
+                        && focus != Focus::TagEdit
+                        && focus != Focus::Filesystems
+                        && !content_mode
+                    {
                         if let Some(index) = filtered.get(selected) {
                             let value = items[*index].clone();
+                            record_visit(&value);
                             terminal.show_cursor()?;
                             return Ok(Some(value));
                         }
                     }
                     if key.code == KeyCode::Char('s')
                         && key.modifiers.contains(KeyModifiers::CONTROL)
+                        && !content_mode
                     {
                         sort_mode = sort_mode.next();
                         filtered = filter_and_sort(
-                            items,
+                            &items,
                             input.value(),
                             sort_mode,
                             &meta_cache,
                             &tag_cache,
+                            &frecency_cache,
                         );
                         selected = 0;
                         list_offset = 0;
                         if sort_mode.uses_time() {
                             spawn_bulk_metadata_fetch(
-                                items,
+                                &items,
                                 &date_cache,
                                 &mut date_in_flight,
                                 &date_tx,
                             );
                         }
                         if parse_query_tokens(input.value()).needs_tags() && !tag_scan_started {
-                            spawn_bulk_tag_fetch(items, &tag_cache, &mut tag_in_flight, &tag_tx);
+                            spawn_bulk_tag_fetch(&items, &tag_cache, &mut tag_in_flight, &tag_tx);
                             tag_scan_started = true;
                         }
                         continue;
                     }
 
                     match focus {
+                        Focus::Search if content_mode => match key.code {
+                            KeyCode::Up => {
+                                if content_selected > 0 {
+                                    content_selected -= 1;
+                                }
+                            }
+                            KeyCode::Down => {
+                                if content_selected + 1 < content_hits.len() {
+                                    content_selected += 1;
+                                }
+                            }
+                            _ => {
+                                let before = input.value().to_string();
+                                if key.code == KeyCode::Char('u')
+                                    && key.modifiers.contains(KeyModifiers::CONTROL)
+                                {
+                                    input.handle(InputRequest::DeleteLine);
+                                } else {
+                                    let _ = input.handle_event(&Event::Key(key));
+                                }
+                                if input.value() != before {
+                                    content_dirty_since = Some(Instant::now());
+                                }
+                            }
+                        },
                         Focus::Search => match key.code {
                             KeyCode::Up => {
                                 if selected > 0 {
@@ -806,11 +2069,12 @@ fn select_from_list(_title: &str, items: &[String]) -> AppResult<Option<String>>
                                 }
                                 if input.value() != before {
                                     filtered = filter_and_sort(
-                                        items,
+                                        &items,
                                         input.value(),
                                         sort_mode,
                                         &meta_cache,
                                         &tag_cache,
+                                        &frecency_cache,
                                     );
                                     selected = 0;
                                     list_offset = 0;
@@ -823,6 +2087,7 @@ fn select_from_list(_title: &str, items: &[String]) -> AppResult<Option<String>>
                                     &mut tag_input,
                                     &mut tag_edit_tags,
                                     &tag_suggestions,
+                                    false,
                                 );
                                 if let Some(path) = tag_edit_path.clone() {
                                     save_tags_for_path(&path, &tag_edit_tags)?;
@@ -833,17 +2098,18 @@ fn select_from_list(_title: &str, items: &[String]) -> AppResult<Option<String>>
                                 tag_edit_tags.clear();
                                 tag_input.reset();
                                 let selected_path =
-                                    current_selection_path(items, &filtered, selected);
+                                    current_selection_path(&items, &filtered, selected);
                                 filtered = filter_and_sort(
-                                    items,
+                                    &items,
                                     input.value(),
                                     sort_mode,
                                     &meta_cache,
                                     &tag_cache,
+                                    &frecency_cache,
                                 );
                                 selected = match selected_path {
                                     Some(value) => {
-                                        index_for_path(items, &filtered, &value).unwrap_or(0)
+                                        index_for_path(&items, &filtered, &value).unwrap_or(0)
                                     }
                                     None => adjust_selected_index(selected, filtered.len()),
                                 };
@@ -853,7 +2119,15 @@ fn select_from_list(_title: &str, items: &[String]) -> AppResult<Option<String>>
                                     &mut tag_input,
                                     &mut tag_edit_tags,
                                     &tag_suggestions,
+                                    true,
                                 );
+                                if let Some(path) = tag_edit_path.clone() {
+                                    tag_suggestions = collect_tag_suggestions(
+                                        &tag_cache,
+                                        &path,
+                                        &tag_edit_tags,
+                                    );
+                                }
                             }
                             KeyCode::Backspace => {
                                 if tag_input.value().is_empty() {
@@ -936,6 +2210,114 @@ fn select_from_list(_title: &str, items: &[String]) -> AppResult<Option<String>>
                             KeyCode::End => {
                                 git_scroll = git_max_scroll;
                             }
+                            KeyCode::Enter => {
+                                if let Some(path) = current.clone() {
+                                    if let Some((_, oid)) = git_commit_rows
+                                        .iter()
+                                        .rev()
+                                        .find(|(line, _)| *line <= git_scroll)
+                                    {
+                                        let oid = oid.clone();
+                                        if !git_commit_expanded.insert(oid.clone()) {
+                                            git_commit_expanded.remove(&oid);
+                                        }
+                                        git_text = Some(build_placeholder_text(
+                                            Some(&path),
+                                            accent,
+                                            muted,
+                                            text,
+                                            "Loading git info...",
+                                        ));
+                                        let tx = preview_tx.clone();
+                                        let path_owned = path.clone();
+                                        let expanded_commits = git_commit_expanded.clone();
+                                        let base_data = preview_cache.get(&path).cloned();
+                                        thread::spawn(move || {
+                                            let (git, git_commit_rows) = match build_git_text(
+                                                &path_owned,
+                                                &theme,
+                                                &expanded_commits,
+                                            ) {
+                                                Some((git, rows)) => (Some(git), rows),
+                                                None => (None, Vec::new()),
+                                            };
+                                            let data = match base_data {
+                                                Some(mut data) => {
+                                                    data.git = git;
+                                                    data.git_commit_rows = git_commit_rows;
+                                                    data
+                                                }
+                                                None => PreviewData {
+                                                    preview: build_placeholder_text(
+                                                        Some(&path_owned),
+                                                        accent,
+                                                        muted,
+                                                        text,
+                                                        "Loading preview...",
+                                                    ),
+                                                    git,
+                                                    git_commit_rows,
+                                                    image: None,
+                                                    modified: path_modified(&path_owned),
+                                                },
+                                            };
+                                            let _ = tx.send(PreviewResult {
+                                                path: path_owned,
+                                                data,
+                                            });
+                                        });
+                                    }
+                                }
+                            }
+                            KeyCode::Char('[') => {
+                                if let Some(git) = &git_text {
+                                    let hunks = hunk_boundaries(git);
+                                    if let Some(prev) =
+                                        hunks.iter().rev().find(|&&h| h < git_scroll)
+                                    {
+                                        git_scroll = *prev;
+                                    }
+                                }
+                            }
+                            KeyCode::Char(']') => {
+                                if let Some(git) = &git_text {
+                                    let hunks = hunk_boundaries(git);
+                                    if let Some(next) = hunks.iter().find(|&&h| h > git_scroll) {
+                                        git_scroll = *next;
+                                    }
+                                }
+                            }
+                            _ => {}
+                        },
+                        Focus::Filesystems => match key.code {
+                            KeyCode::Up => {
+                                if fs_selected > 0 {
+                                    fs_selected -= 1;
+                                }
+                            }
+                            KeyCode::Down => {
+                                if fs_selected + 1 < mounts.len() {
+                                    fs_selected += 1;
+                                }
+                            }
+                            KeyCode::Enter => {
+                                if let Some(mount) = mounts.get(fs_selected).cloned() {
+                                    items = build_items_with_root(&mount.mount_point)?;
+                                    input.reset();
+                                    selected = 0;
+                                    list_offset = 0;
+                                    filtered = filter_and_sort(
+                                        &items,
+                                        input.value(),
+                                        sort_mode,
+                                        &meta_cache,
+                                        &tag_cache,
+                                        &frecency_cache,
+                                    );
+                                    content_mode = false;
+                                    focus = Focus::Search;
+                                }
+                            }
                             _ => {}
                         },
                     }
@@ -1025,25 +2407,90 @@ fn filter_and_sort_by_match(
     if tokens.is_empty() {
         return (0..items.len()).collect();
     }
-    let mut scored: Vec<(usize, (usize, usize, usize, usize, usize))> = Vec::new();
+    let mut scored: Vec<(usize, i64)> = Vec::new();
     for (index, path) in items.iter().enumerate() {
         let tags = tag_cache.get(path).map(Vec::as_slice).unwrap_or(&[]);
         if !matches_tokens(path, tags, &tokens) {
             continue;
         }
-        if let Some(score) = match_score_tokens(&tokens, path, tags) {
+        if let Some((score, _)) = match_score_tokens(&tokens, path, tags) {
             scored.push((index, score));
         }
     }
-    scored.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+    scored.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
     scored.into_iter().map(|(index, _)| index).collect()
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum MatchKind {
+    Fuzzy,
+    Prefix,
+    Substring,
+    Exact,
+    Postfix,
+}
+
+struct QueryAtom {
+    kind: MatchKind,
+    needle: String,
+    inverse: bool,
+}
+
+impl QueryAtom {
+    fn parse(raw: &str) -> Option<QueryAtom> {
+        let (inverse, rest) = match raw.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None => (false, raw),
+        };
+        if rest.is_empty() {
+            return None;
+        }
+
+        let mut chars = rest.chars();
+        let first = chars.next()?;
+        let (mut kind, mut needle) = match first {
+            '^' => (MatchKind::Prefix, chars.as_str().to_string()),
+            '\'' => (MatchKind::Substring, chars.as_str().to_string()),
+            _ => (MatchKind::Fuzzy, rest.to_string()),
+        };
+
+        if let Some(stripped) = needle.strip_suffix('$') {
+            needle = stripped.to_string();
+            kind = match kind {
+                MatchKind::Prefix => MatchKind::Exact,
+                _ => MatchKind::Postfix,
+            };
+        }
+
+        if needle.is_empty() {
+            return None;
+        }
+
+        Some(QueryAtom {
+            kind,
+            needle,
+            inverse,
+        })
+    }
+
+    fn raw_matches(&self, text: &str) -> bool {
+        let text_lower = text.to_lowercase();
+        let needle_lower = self.needle.to_lowercase();
+        match self.kind {
+            MatchKind::Fuzzy => fuzzy_match(&self.needle, text),
+            MatchKind::Prefix => text_lower.starts_with(&needle_lower),
+            MatchKind::Postfix => text_lower.ends_with(&needle_lower),
+            MatchKind::Exact => text_lower == needle_lower,
+            MatchKind::Substring => text_lower.contains(&needle_lower),
+        }
+    }
+}
+
 #[derive(Default)]
 struct QueryTokens {
-    folder: Vec<String>,
-    tags: Vec<String>,
-    any: Vec<String>,
+    folder: Vec<QueryAtom>,
+    tags: Vec<QueryAtom>,
+    any: Vec<QueryAtom>,
 }
 
 impl QueryTokens {
@@ -1060,37 +2507,35 @@ fn parse_query_tokens(query: &str) -> QueryTokens {
     let mut tokens = QueryTokens::default();
     for raw in query.split_whitespace() {
         if let Some(rest) = raw.strip_prefix('@') {
-            if !rest.is_empty() {
-                tokens.folder.push(rest.to_string());
+            if let Some(atom) = QueryAtom::parse(rest) {
+                tokens.folder.push(atom);
             }
         } else if let Some(rest) = raw.strip_prefix('#') {
-            if !rest.is_empty() {
-                tokens.tags.push(rest.to_string());
+            if let Some(atom) = QueryAtom::parse(rest) {
+                tokens.tags.push(atom);
             }
-        } else if !raw.is_empty() {
-            tokens.any.push(raw.to_string());
+        } else if let Some(atom) = QueryAtom::parse(raw) {
+            tokens.any.push(atom);
         }
     }
     tokens
 }
 
 fn matches_tokens(path: &str, tags: &[String], tokens: &QueryTokens) -> bool {
-    for token in &tokens.folder {
-        if !matches_path_token(token, path) {
+    for atom in &tokens.folder {
+        if !matches_path_atom(atom, path) {
             return false;
         }
     }
 
-    for token in &tokens.tags {
-        if !tags.iter().any(|tag| fuzzy_match(token, tag)) {
+    for atom in &tokens.tags {
+        if !matches_tag_atom(atom, tags) {
             return false;
         }
     }
 
-    for token in &tokens.any {
-        let path_match = matches_path_token(token, path);
-        let tag_match = tags.iter().any(|tag| fuzzy_match(token, tag));
-        if !(path_match || tag_match) {
+    for atom in &tokens.any {
+        if !matches_any_atom(atom, path, tags) {
             return false;
         }
     }
@@ -1098,85 +2543,90 @@ fn matches_tokens(path: &str, tags: &[String], tokens: &QueryTokens) -> bool {
     true
 }
 
-fn matches_path_token(token: &str, path: &str) -> bool {
+fn matches_path_atom(atom: &QueryAtom, path: &str) -> bool {
+    let entry = entry_name(path);
+    let hit = atom.raw_matches(&entry) || atom.raw_matches(path);
+    hit ^ atom.inverse
+}
+
+fn matches_tag_atom(atom: &QueryAtom, tags: &[String]) -> bool {
+    let hit = tags.iter().any(|tag| atom.raw_matches(tag));
+    hit ^ atom.inverse
+}
+
+fn matches_any_atom(atom: &QueryAtom, path: &str, tags: &[String]) -> bool {
     let entry = entry_name(path);
-    fuzzy_match(token, &entry) || fuzzy_match(token, path)
+    let hit = atom.raw_matches(&entry)
+        || atom.raw_matches(path)
+        || tags.iter().any(|tag| atom.raw_matches(tag));
+    hit ^ atom.inverse
 }
 
+const PARENT_SEGMENT_PENALTY: i64 = 40;
+
 fn match_score_tokens(
     tokens: &QueryTokens,
     path: &str,
     tags: &[String],
-) -> Option<(usize, usize, usize, usize, usize)> {
-    let mut penalty_sum = 0usize;
-    let mut span_sum = 0usize;
-    let mut gap_sum = 0usize;
-    let mut start_sum = 0usize;
-    let mut len_sum = 0usize;
-
-    for token in &tokens.folder {
-        let score = match_score_for_path(token, path)?;
-        penalty_sum = penalty_sum.saturating_add(score.0);
-        span_sum = span_sum.saturating_add(score.1);
-        gap_sum = gap_sum.saturating_add(score.2);
-        start_sum = start_sum.saturating_add(score.3);
-        len_sum = len_sum.saturating_add(score.4);
-    }
-
-    for token in &tokens.tags {
-        let score = best_tag_score(token, tags)?;
-        penalty_sum = penalty_sum.saturating_add(score.0);
-        span_sum = span_sum.saturating_add(score.1);
-        gap_sum = gap_sum.saturating_add(score.2);
-        start_sum = start_sum.saturating_add(score.3);
-        len_sum = len_sum.saturating_add(score.4);
-    }
-
-    for token in &tokens.any {
-        let mut best = match_score_for_path(token, path);
-        if let Some(tag_score) = best_tag_score(token, tags) {
-            best = match best {
-                Some(path_score) => Some(path_score.min(tag_score)),
-                None => Some(tag_score),
-            };
+) -> Option<(i64, Vec<usize>)> {
+    let mut total = 0i64;
+    let mut positions: HashSet<usize> = HashSet::new();
+
+    for atom in &tokens.folder {
+        if atom.inverse {
+            continue;
         }
-        let score = best?;
-        penalty_sum = penalty_sum.saturating_add(score.0);
-        span_sum = span_sum.saturating_add(score.1);
-        gap_sum = gap_sum.saturating_add(score.2);
-        start_sum = start_sum.saturating_add(score.3);
-        len_sum = len_sum.saturating_add(score.4);
+        let (score, pos) = match_score_for_path(&atom.needle, path)?;
+        total = total.saturating_add(score);
+        positions.extend(pos);
     }
 
-    Some((penalty_sum, span_sum, gap_sum, start_sum, len_sum))
-}
+    for atom in &tokens.tags {
+        if atom.inverse {
+            continue;
+        }
+        total = total.saturating_add(best_tag_score(&atom.needle, tags)?);
+    }
 
-fn best_tag_score(token: &str, tags: &[String]) -> Option<(usize, usize, usize, usize, usize)> {
-    let mut best: Option<(usize, usize, usize, usize, usize)> = None;
-    for tag in tags {
-        if let Some(score) = match_score(token, tag) {
-            best = match best {
-                Some(current) => Some(current.min(score)),
-                None => Some(score),
-            };
+    for atom in &tokens.any {
+        if atom.inverse {
+            continue;
+        }
+        let path_score = match_score_for_path(&atom.needle, path);
+        let tag_score = best_tag_score(&atom.needle, tags);
+        match (path_score, tag_score) {
+            (Some((p_score, pos)), Some(t_score)) if p_score >= t_score => {
+                total = total.saturating_add(p_score);
+                positions.extend(pos);
+            }
+            (Some(_), Some(t_score)) => total = total.saturating_add(t_score),
+            (Some((p_score, pos)), None) => {
+                total = total.saturating_add(p_score);
+                positions.extend(pos);
+            }
+            (None, Some(t_score)) => total = total.saturating_add(t_score),
+            (None, None) => return None,
         }
     }
-    best
+
+    let mut positions: Vec<usize> = positions.into_iter().collect();
+    positions.sort_unstable();
+    Some((total, positions))
+}
+
+fn best_tag_score(token: &str, tags: &[String]) -> Option<i64> {
+    tags.iter()
+        .filter_map(|tag| match_score(token, tag).map(|(score, _)| score))
+        .max()
 }
 
-fn match_score_for_path(token: &str, path: &str) -> Option<(usize, usize, usize, usize, usize)> {
+fn match_score_for_path(token: &str, path: &str) -> Option<(i64, Vec<usize>)> {
     let entry = entry_name(path);
     if let Some(score) = match_score(token, &entry) {
         return Some(score);
     }
-    if let Some(score) = match_score(token, path) {
-        return Some((
-            score.0.saturating_add(2),
-            score.1,
-            score.2,
-            score.3,
-            score.4,
-        ));
+    if let Some((score, _)) = match_score(token, path) {
+        return Some((score.saturating_sub(PARENT_SEGMENT_PENALTY), Vec::new()));
     }
     None
 }
@@ -1187,12 +2637,13 @@ fn filter_and_sort(
     sort_mode: SortMode,
     meta_cache: &HashMap<String, SortMeta>,
     tag_cache: &HashMap<String, Vec<String>>,
+    frecency_cache: &HashMap<String, Vec<u64>>,
 ) -> Vec<usize> {
     if sort_mode == SortMode::Match {
         return filter_and_sort_by_match(items, query, tag_cache);
     }
     let mut indices = filter_indices(items, query, tag_cache);
-    sort_indices(&mut indices, items, sort_mode, meta_cache);
+    sort_indices(&mut indices, items, sort_mode, meta_cache, frecency_cache);
     indices
 }
 
@@ -1201,8 +2652,9 @@ fn sort_indices(
     items: &[String],
     sort_mode: SortMode,
     meta_cache: &HashMap<String, SortMeta>,
+    frecency_cache: &HashMap<String, Vec<u64>>,
 ) {
-    indices.sort_by(|a, b| compare_indices(*a, *b, items, sort_mode, meta_cache));
+    indices.sort_by(|a, b| compare_indices(*a, *b, items, sort_mode, meta_cache, frecency_cache));
 }
 
 fn compare_indices(
@@ -1211,6 +2663,7 @@ fn compare_indices(
     items: &[String],
     sort_mode: SortMode,
     meta_cache: &HashMap<String, SortMeta>,
+    frecency_cache: &HashMap<String, Vec<u64>>,
 ) -> Ordering {
     let left_path = &items[left];
     let right_path = &items[right];
@@ -1233,9 +2686,33 @@ fn compare_indices(
             compare_time(right_path, left_path, meta_cache, TimeField::Modified)
                 .then_with(|| compare_names(left_path, right_path))
         }
+        SortMode::Frecency => compare_frecency(left_path, right_path, frecency_cache)
+            .then_with(|| compare_names(left_path, right_path)),
     }
 }
 
+fn compare_frecency(
+    left: &str,
+    right: &str,
+    frecency_cache: &HashMap<String, Vec<u64>>,
+) -> Ordering {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let left_score = frecency_cache
+        .get(left)
+        .map(|visits| frecency_score(visits, now))
+        .unwrap_or(0.0);
+    let right_score = frecency_cache
+        .get(right)
+        .map(|visits| frecency_score(visits, now))
+        .unwrap_or(0.0);
+    right_score
+        .partial_cmp(&left_score)
+        .unwrap_or(Ordering::Equal)
+}
+
 fn compare_names(left: &str, right: &str) -> Ordering {
     let left_name = entry_name(left).to_lowercase();
     let right_name = entry_name(right).to_lowercase();
@@ -1284,6 +2761,8 @@ fn index_for_path(items: &[String], filtered: &[usize], path: &str) -> Option<us
 
 fn build_help_line(
     focus: Focus,
+    content_mode: bool,
+    theme_picker_active: bool,
     sort_mode: SortMode,
     show_git: bool,
     cursor_at_end: bool,
@@ -1291,16 +2770,34 @@ fn build_help_line(
     preview_scroll: usize,
     preview_max_scroll: usize,
     git_scroll: usize,
-    text: Color,
-    accent: Color,
-    key_color: Color,
+    theme: &Theme,
 ) -> Line<'static> {
-    let key_style = Style::default().fg(key_color).add_modifier(Modifier::BOLD);
-    let label_style = Style::default().fg(accent).add_modifier(Modifier::BOLD);
-    let regular_style = Style::default().fg(text);
+    let key_style = Style::default().fg(theme.key).add_modifier(Modifier::BOLD);
+    let label_style = Style::default().fg(theme.accent).add_modifier(Modifier::BOLD);
+    let regular_style = Style::default().fg(theme.text);
     let mut spans: Vec<Span> = Vec::new();
 
     match focus {
+        Focus::Search if theme_picker_active => {
+            spans.push(Span::styled("Theme", label_style));
+            spans.push(Span::styled("  ", regular_style));
+            spans.push(Span::styled("Up/Down", key_style));
+            spans.push(Span::styled(" preview  ", regular_style));
+            spans.push(Span::styled("Enter", key_style));
+            spans.push(Span::styled(" apply  ", regular_style));
+            spans.push(Span::styled("Esc", key_style));
+            spans.push(Span::styled(" cancel", regular_style));
+        }
+        Focus::Search if content_mode => {
+            spans.push(Span::styled("Content search", label_style));
+            spans.push(Span::styled("  ", regular_style));
+            spans.push(Span::styled("Enter", key_style));
+            spans.push(Span::styled(" open hit  ", regular_style));
+            spans.push(Span::styled("Ctrl+G", key_style));
+            spans.push(Span::styled(" back to names  ", regular_style));
+            spans.push(Span::styled("Esc", key_style));
+            spans.push(Span::styled(" cancel", regular_style));
+        }
         Focus::Search => {
             spans.push(Span::styled("Search", label_style));
             spans.push(Span::styled("  ", regular_style));
@@ -1310,6 +2807,12 @@ fn build_help_line(
             }
             spans.push(Span::styled("Ctrl+T", key_style));
             spans.push(Span::styled(" tag  ", regular_style));
+            spans.push(Span::styled("Ctrl+F", key_style));
+            spans.push(Span::styled(" filesystems  ", regular_style));
+            spans.push(Span::styled("Ctrl+G", key_style));
+            spans.push(Span::styled(" content search  ", regular_style));
+            spans.push(Span::styled("Ctrl+P", key_style));
+            spans.push(Span::styled(" theme  ", regular_style));
             spans.push(Span::styled("Ctrl+S", key_style));
             spans.push(Span::styled(
                 format!(" {}  ", sort_mode.label()),
@@ -1345,6 +2848,8 @@ fn build_help_line(
             spans.push(Span::styled(" search  ", regular_style));
             spans.push(Span::styled("Right", key_style));
             spans.push(Span::styled(" preview  ", regular_style));
+            spans.push(Span::styled("Enter", key_style));
+            spans.push(Span::styled(" fold/unfold  ", regular_style));
             spans.push(Span::styled("Ctrl+T", key_style));
             spans.push(Span::styled(" tag  ", regular_style));
             if git_scroll == 0 {
@@ -1364,6 +2869,14 @@ fn build_help_line(
                 spans.push(Span::styled(" done", regular_style));
             }
         }
+        Focus::Filesystems => {
+            spans.push(Span::styled("Filesystems", label_style));
+            spans.push(Span::styled("  ", regular_style));
+            spans.push(Span::styled("Enter", key_style));
+            spans.push(Span::styled(" jump  ", regular_style));
+            spans.push(Span::styled("Esc", key_style));
+            spans.push(Span::styled(" back", regular_style));
+        }
     }
 
     Line::from(spans)
@@ -1380,7 +2893,7 @@ struct UiLayout {
     help_area: Rect,
 }
 
-fn compute_ui_layout(size: Rect, show_git: bool) -> UiLayout {
+fn compute_ui_layout(size: Rect, show_git: bool, has_footer: bool) -> UiLayout {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Min(8), Constraint::Length(3)])
@@ -1403,14 +2916,24 @@ fn compute_ui_layout(size: Rect, show_git: bool) -> UiLayout {
     let search_area = left_chunks[0];
     let results_area = left_chunks[1];
 
+    let content_area = if has_footer {
+        let split = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(3), Constraint::Length(1)])
+            .split(detail_area);
+        split[0]
+    } else {
+        detail_area
+    };
+
     let (preview_area, git_area) = if show_git {
         let panels = Layout::default()
             .direction(Direction::Vertical)
             .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
-            .split(detail_area);
+            .split(content_area);
         (panels[0], Some(panels[1]))
     } else {
-        (detail_area, None)
+        (content_area, None)
     };
 
     UiLayout {
@@ -1468,44 +2991,131 @@ fn fuzzy_match(query: &str, text: &str) -> bool {
     false
 }
 
-fn match_score(query: &str, text: &str) -> Option<(usize, usize, usize, usize, usize)> {
+const SCORE_MATCH: i64 = 16;
+const BONUS_BOUNDARY: i64 = 10;
+const BONUS_CAMEL: i64 = 8;
+const BONUS_CONSECUTIVE: i64 = 12;
+const PENALTY_GAP: i64 = 2;
+
+/// fzf/skim-style subsequence scoring: matches `query` fuzzily against `text` and
+/// returns the total score plus the char indices (into `text`) of the match placement.
+/// When `query` occurs in `text` as a contiguous case-insensitive substring, its first
+/// occurrence is used and scored as one consecutive run; other placements are not
+/// compared. Otherwise the highest-scoring subsequence placement from the DP is used.
+fn match_score(query: &str, text: &str) -> Option<(i64, Vec<usize>)> {
     let qchars: Vec<char> = query.chars().filter(|c| !c.is_whitespace()).collect();
     if qchars.is_empty() {
-        return Some((0, 0, 0, 0, text.chars().count()));
+        return Some((0, Vec::new()));
     }
 
     if let Some(start) = find_case_insensitive(text, query) {
-        let span = qchars.len().saturating_sub(1);
-        return Some((0, span, 0, start, text.chars().count()));
+        let positions: Vec<usize> = (start..start + qchars.len()).collect();
+        let boundary = char_boundary_bonus(text, start);
+        let score = SCORE_MATCH * qchars.len() as i64
+            + boundary
+            + BONUS_CONSECUTIVE * qchars.len().saturating_sub(1) as i64;
+        return Some((score, positions));
+    }
+
+    fuzzy_dp_score(&qchars, text)
+}
+
+fn char_boundary_bonus(text: &str, index: usize) -> i64 {
+    let chars: Vec<char> = text.chars().collect();
+    boundary_bonus(&chars, index)
+}
+
+fn boundary_bonus(chars: &[char], index: usize) -> i64 {
+    if index == 0 {
+        return BONUS_BOUNDARY;
+    }
+    let prev = chars[index - 1];
+    let cur = chars[index];
+    if prev == '/' || prev == '_' || prev == '-' || prev == ' ' || prev == '.' {
+        BONUS_BOUNDARY
+    } else if prev.is_lowercase() && cur.is_uppercase() {
+        BONUS_CAMEL
+    } else {
+        0
+    }
+}
+
+/// Small DP pass over `text`'s characters: `best[j]` tracks the highest score
+/// achievable matching the first `j+1` query chars, ending at each candidate
+/// position, so the best match placement overall can be recovered via backtracking.
+fn fuzzy_dp_score(qchars: &[char], text: &str) -> Option<(i64, Vec<usize>)> {
+    let cchars: Vec<char> = text.chars().collect();
+    let qn = qchars.len();
+    let cn = cchars.len();
+    if cn == 0 {
+        return None;
     }
 
-    let mut positions: Vec<usize> = Vec::with_capacity(qchars.len());
+    // Confirm query is a subsequence of text before paying for the full DP pass.
     let mut qi = 0usize;
-    for (ti, t) in text.chars().enumerate() {
-        if qi >= qchars.len() {
-            break;
-        }
-        if qchars[qi].eq_ignore_ascii_case(&t) {
-            positions.push(ti);
+    for ch in &cchars {
+        if qi < qn && qchars[qi].eq_ignore_ascii_case(ch) {
             qi += 1;
         }
     }
+    if qi < qn {
+        return None;
+    }
+
+    const NEG_INF: i64 = i64::MIN / 2;
+    let mut best = vec![vec![NEG_INF; cn]; qn];
+    let mut back = vec![vec![usize::MAX; cn]; qn];
+
+    for i in 0..cn {
+        if qchars[0].eq_ignore_ascii_case(&cchars[i]) {
+            best[0][i] = SCORE_MATCH + boundary_bonus(&cchars, i);
+        }
+    }
+
+    for j in 1..qn {
+        for i in 0..cn {
+            if !qchars[j].eq_ignore_ascii_case(&cchars[i]) {
+                continue;
+            }
+            let mut best_prev = NEG_INF;
+            let mut best_prev_idx = usize::MAX;
+            for k in 0..i {
+                if best[j - 1][k] <= NEG_INF {
+                    continue;
+                }
+                let gap = i - k - 1;
+                let candidate = best[j - 1][k] - gap as i64 * PENALTY_GAP
+                    + if gap == 0 { BONUS_CONSECUTIVE } else { 0 };
+                if candidate > best_prev {
+                    best_prev = candidate;
+                    best_prev_idx = k;
+                }
+            }
+            if best_prev > NEG_INF {
+                best[j][i] = best_prev + SCORE_MATCH + boundary_bonus(&cchars, i);
+                back[j][i] = best_prev_idx;
+            }
+        }
+    }
 
-    if qi < qchars.len() {
+    let (end, score) = (0..cn)
+        .map(|i| (i, best[qn - 1][i]))
+        .max_by_key(|(_, score)| *score)?;
+    if score <= NEG_INF {
         return None;
     }
 
-    let start = *positions.first().unwrap_or(&0);
-    let end = *positions.last().unwrap_or(&start);
-    let span = end.saturating_sub(start);
-    let mut gaps = 0usize;
-    for window in positions.windows(2) {
-        if let [prev, next] = window {
-            gaps = gaps.saturating_add(next.saturating_sub(prev + 1));
+    let mut positions = vec![0usize; qn];
+    let mut cur = end;
+    for j in (0..qn).rev() {
+        positions[j] = cur;
+        if back[j][cur] == usize::MAX {
+            break;
         }
+        cur = back[j][cur];
     }
-    let text_len = text.chars().count();
-    Some((1, span, gaps, start, text_len))
+
+    Some((score, positions))
 }
 
 fn find_case_insensitive(text: &str, needle: &str) -> Option<usize> {
@@ -1566,11 +3176,14 @@ fn build_visible_list_items(
     height: usize,
     text: Color,
     muted: Color,
+    match_highlight: Color,
+    tag_fallback: Color,
     dates: &HashMap<String, String>,
     tags: &HashMap<String, Vec<String>>,
     inner_width: usize,
     tokens: &QueryTokens,
     elapsed_ms: u64,
+    tag_theme: &TagTheme,
 ) -> (Vec<ListItem<'static>>, Option<usize>) {
     if filtered.is_empty() || height == 0 {
         let item = ListItem::new(Line::from(Span::styled(
@@ -1606,7 +3219,7 @@ fn build_visible_list_items(
         let remaining = inner_width.saturating_sub(entry_len + date_len);
         let tag_space = remaining.saturating_sub(1);
         let (tag_spans, tag_len) = if tag_space > 0 {
-            build_tag_spans(tag_list, tokens, tag_space, elapsed_ms, text)
+            build_tag_spans(tag_list, tokens, tag_space, elapsed_ms, text, tag_fallback, tag_theme)
         } else {
             (Vec::new(), 0)
         };
@@ -1614,7 +3227,7 @@ fn build_visible_list_items(
         let right_block_len = date_len + tag_block_len;
         let padding = inner_width.saturating_sub(entry_len + right_block_len);
         let mut spans = Vec::new();
-        spans.push(Span::styled(entry_display, Style::default().fg(text)));
+        spans.extend(entry_match_spans(&entry_display, tokens, text, match_highlight));
         spans.push(Span::raw(" ".repeat(padding)));
         if tag_len > 0 {
             spans.push(Span::raw(" "));
@@ -1630,6 +3243,74 @@ fn build_visible_list_items(
     (list_items, list_selected)
 }
 
+fn build_content_hit_items(
+    hits: &[ContentHit],
+    selected: usize,
+    offset: usize,
+    height: usize,
+    text: Color,
+    muted: Color,
+) -> (Vec<ListItem<'static>>, Option<usize>) {
+    if hits.is_empty() || height == 0 {
+        let item = ListItem::new(Line::from(Span::styled(
+            "No matches",
+            Style::default().fg(muted),
+        )));
+        return (vec![item], None);
+    }
+
+    let end = (offset + height).min(hits.len());
+    let mut list_items = Vec::with_capacity(end - offset);
+    for hit in &hits[offset..end] {
+        let location = format!("{}:{}", entry_name(&hit.path), hit.line);
+        let line = Line::from(vec![
+            Span::styled(location, Style::default().fg(text).add_modifier(Modifier::BOLD)),
+            Span::raw("  "),
+            Span::styled(hit.preview.clone(), Style::default().fg(muted)),
+        ]);
+        list_items.push(ListItem::new(line));
+    }
+
+    let list_selected = selected.checked_sub(offset);
+    (list_items, list_selected)
+}
+
+fn entry_match_spans(
+    entry_display: &str,
+    tokens: &QueryTokens,
+    text: Color,
+    match_highlight: Color,
+) -> Vec<Span<'static>> {
+    let mut positions: HashSet<usize> = HashSet::new();
+    for atom in tokens.folder.iter().chain(tokens.any.iter()) {
+        if atom.inverse {
+            continue;
+        }
+        if let Some((_, hits)) = match_score(&atom.needle, entry_display) {
+            positions.extend(hits);
+        }
+    }
+    if positions.is_empty() {
+        return vec![Span::styled(
+            entry_display.to_string(),
+            Style::default().fg(text),
+        )];
+    }
+
+    let plain_style = Style::default().fg(text);
+    let match_style = Style::default().fg(match_highlight).add_modifier(Modifier::BOLD);
+    let mut spans = Vec::with_capacity(entry_display.chars().count());
+    for (index, ch) in entry_display.chars().enumerate() {
+        let style = if positions.contains(&index) {
+            match_style
+        } else {
+            plain_style
+        };
+        spans.push(Span::styled(ch.to_string(), style));
+    }
+    spans
+}
+
 fn entry_name(path: &str) -> String {
     Path::new(path)
         .file_name()
@@ -1731,6 +3412,96 @@ fn spawn_bulk_tag_fetch(
     });
 }
 
+fn spawn_content_search(
+    roots: Vec<String>,
+    query: String,
+    generation: u64,
+    tx: mpsc::Sender<ContentResult>,
+) {
+    thread::spawn(move || {
+        let pattern = match ContentPattern::parse(&query) {
+            Some(pattern) => pattern,
+            None => return,
+        };
+        let mut total = 0usize;
+        for root in roots {
+            if total >= CONTENT_MAX_TOTAL {
+                break;
+            }
+            walk_content_search(Path::new(&root), &pattern, generation, &tx, &mut total);
+        }
+    });
+}
+
+fn walk_content_search(
+    dir: &Path,
+    pattern: &ContentPattern,
+    generation: u64,
+    tx: &mpsc::Sender<ContentResult>,
+    total: &mut usize,
+) {
+    if *total >= CONTENT_MAX_TOTAL {
+        return;
+    }
+    let read_dir = match fs::read_dir(dir) {
+        Ok(value) => value,
+        Err(_) => return,
+    };
+    let mut entries: Vec<PathBuf> = read_dir.flatten().map(|entry| entry.path()).collect();
+    entries.sort();
+    for path in entries {
+        if *total >= CONTENT_MAX_TOTAL {
+            return;
+        }
+        let name_is_hidden = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .map(|name| name.starts_with('.'))
+            .unwrap_or(false);
+        if name_is_hidden {
+            continue;
+        }
+        if is_dir(&path) {
+            walk_content_search(&path, pattern, generation, tx, total);
+        } else {
+            search_file_for_hits(&path, pattern, generation, tx, total);
+        }
+    }
+}
+
+fn search_file_for_hits(
+    path: &Path,
+    pattern: &ContentPattern,
+    generation: u64,
+    tx: &mpsc::Sender<ContentResult>,
+    total: &mut usize,
+) {
+    let contents = match fs::read_to_string(path) {
+        Ok(value) => value,
+        Err(_) => return,
+    };
+    let path_display = path.to_string_lossy().to_string();
+    let mut per_file = 0usize;
+    for (index, line) in contents.lines().enumerate() {
+        if per_file >= CONTENT_MAX_PER_FILE || *total >= CONTENT_MAX_TOTAL {
+            return;
+        }
+        if !pattern.is_match(line) {
+            continue;
+        }
+        let hit = ContentHit {
+            path: path_display.clone(),
+            line: index + 1,
+            preview: line.trim().chars().take(200).collect(),
+        };
+        if tx.send(ContentResult { generation, hit }).is_err() {
+            return;
+        }
+        per_file += 1;
+        *total += 1;
+    }
+}
+
 fn read_tags_for_path(path: &str) -> Vec<String> {
     let dir = Path::new(path);
     let config_path = dir.join(".navgator.toml");
@@ -1902,6 +3673,8 @@ fn build_tag_spans(
     max_width: usize,
     elapsed_ms: u64,
     text: Color,
+    tag_fallback: Color,
+    tag_theme: &TagTheme,
 ) -> (Vec<Span<'static>>, usize) {
     if tags.is_empty() || max_width == 0 {
         return (Vec::new(), 0);
@@ -1914,7 +3687,11 @@ fn build_tag_spans(
         ordered.extend_from_slice(tags);
     } else {
         for tag in tags {
-            if tokens.tags.iter().any(|token| fuzzy_match(token, tag)) {
+            if tokens
+                .tags
+                .iter()
+                .any(|atom| !atom.inverse && atom.raw_matches(tag))
+            {
                 matching.push(tag.clone());
             } else {
                 non_matching.push(tag.clone());
@@ -1925,7 +3702,7 @@ fn build_tag_spans(
     }
     let has_tag_query_match = !matching.is_empty();
 
-    let segments = build_tag_segments(&ordered, text);
+    let segments = build_tag_segments(&ordered, tag_fallback, tag_theme);
     let total_len = segments_total_len(&segments);
     let display_width = max_width.max(1);
     let scroll_enabled =
@@ -1965,7 +3742,7 @@ struct TagSegment {
     len: usize,
 }
 
-fn build_tag_segments(tags: &[String], fallback: Color) -> Vec<TagSegment> {
+fn build_tag_segments(tags: &[String], fallback: Color, tag_theme: &TagTheme) -> Vec<TagSegment> {
     let mut segments = Vec::new();
     for (index, tag) in tags.iter().enumerate() {
         if index > 0 {
@@ -1976,7 +3753,7 @@ fn build_tag_segments(tags: &[String], fallback: Color) -> Vec<TagSegment> {
             });
         }
         let pill = format!("[{}]", tag);
-        let color = tag_color(tag, fallback);
+        let color = tag_color(tag, fallback, tag_theme);
         let style = Style::default().fg(color).add_modifier(Modifier::ITALIC);
         segments.push(TagSegment {
             text: pill.clone(),
@@ -2043,14 +3820,29 @@ fn compose_preview_text_with_input(
     input: &Input,
     width: usize,
     text: Color,
+    tag_fallback: Color,
+    suggestions: &[String],
+    tag_theme: &TagTheme,
 ) -> (Text<'static>, Option<(usize, usize)>) {
-    let tag_lines = build_full_tag_lines(tags, width, text);
+    let tag_lines = build_full_tag_lines(tags, width, tag_fallback, tag_theme);
     let input_line_index = tag_lines.len();
     let scroll = input.visual_scroll(width.max(1));
     let input_slice = substring_by_char(input.value(), scroll, width.max(1));
-    let input_line = Line::from(Span::styled(input_slice, Style::default().fg(text)));
     let cursor_col = input.visual_cursor().max(scroll).saturating_sub(scroll);
 
+    let mut input_spans = vec![Span::styled(input_slice, Style::default().fg(text))];
+    if let Some(guess) = best_tag_suggestion(input.value(), suggestions) {
+        let typed_len = input.value().chars().count();
+        let ghost: String = guess.chars().skip(typed_len).collect();
+        if !ghost.is_empty() {
+            input_spans.push(Span::styled(
+                ghost,
+                Style::default().fg(text).add_modifier(Modifier::DIM),
+            ));
+        }
+    }
+    let input_line = Line::from(input_spans);
+
     let mut lines = Vec::new();
     lines.extend(tag_lines);
     lines.push(input_line);
@@ -2060,34 +3852,81 @@ fn compose_preview_text_with_input(
     (Text::from(lines), cursor)
 }
 
-fn collect_tag_suggestions(tag_cache: &HashMap<String, Vec<String>>) -> Vec<String> {
-    let mut set = HashSet::new();
-    for tags in tag_cache.values() {
+fn collect_tag_suggestions(
+    tag_cache: &HashMap<String, Vec<String>>,
+    tag_edit_path: &str,
+    current_tags: &[String],
+) -> Vec<String> {
+    let parent = Path::new(tag_edit_path).parent();
+    let mut sibling_freq: HashMap<String, usize> = HashMap::new();
+    let mut global_freq: HashMap<String, usize> = HashMap::new();
+    let mut co_occurrence: HashMap<String, usize> = HashMap::new();
+
+    for (path, tags) in tag_cache {
+        let is_sibling = parent.is_some() && Path::new(path).parent() == parent;
+        let shares_current_tag = current_tags.iter().any(|tag| tags.contains(tag));
         for tag in tags {
-            if tag.starts_with("org/") {
+            if tag.starts_with("org/") || current_tags.iter().any(|current| current == tag) {
                 continue;
             }
-            set.insert(tag.clone());
+            *global_freq.entry(tag.clone()).or_insert(0) += 1;
+            if is_sibling {
+                *sibling_freq.entry(tag.clone()).or_insert(0) += 1;
+            }
+            if shares_current_tag {
+                *co_occurrence.entry(tag.clone()).or_insert(0) += 1;
+            }
         }
     }
-    let mut list: Vec<String> = set.into_iter().collect();
-    list.sort();
-    list
+
+    let mut ranked: Vec<String> = global_freq.keys().cloned().collect();
+    ranked.sort_by(|a, b| {
+        sibling_freq
+            .get(b)
+            .unwrap_or(&0)
+            .cmp(sibling_freq.get(a).unwrap_or(&0))
+            .then_with(|| {
+                co_occurrence
+                    .get(b)
+                    .unwrap_or(&0)
+                    .cmp(co_occurrence.get(a).unwrap_or(&0))
+            })
+            .then_with(|| global_freq.get(b).unwrap_or(&0).cmp(global_freq.get(a).unwrap_or(&0)))
+            .then_with(|| a.cmp(b))
+    });
+    ranked
 }
 
-fn commit_tag_input(input: &mut Input, tags: &mut Vec<String>, suggestions: &[String]) {
-    let raw = input.value().trim();
-    if raw.is_empty() {
-        return;
+fn best_tag_suggestion(typed: &str, suggestions: &[String]) -> Option<String> {
+    let trimmed = typed.trim();
+    if trimmed.is_empty() {
+        return suggestions.first().cloned();
     }
-    let mut chosen = raw.to_string();
-    let lower = raw.to_lowercase();
-    if let Some(match_tag) = suggestions
+    let lower = trimmed.to_lowercase();
+    suggestions
         .iter()
         .find(|tag| tag.to_lowercase().starts_with(&lower))
-    {
-        chosen = match_tag.clone();
-    }
+        .cloned()
+}
+
+fn commit_tag_input(
+    input: &mut Input,
+    tags: &mut Vec<String>,
+    suggestions: &[String],
+    allow_best_guess: bool,
+) {
+    let raw = input.value().trim();
+    let chosen = if raw.is_empty() {
+        if !allow_best_guess {
+            return;
+        }
+        match best_tag_suggestion(raw, suggestions) {
+            Some(top) => top,
+            None => return,
+        }
+    } else {
+        best_tag_suggestion(raw, suggestions).unwrap_or_else(|| raw.to_string())
+    };
     if !tags.iter().any(|tag| tag == &chosen) {
         tags.push(chosen);
     }
@@ -2162,13 +4001,14 @@ fn compose_preview_text(
     base: &Text<'static>,
     tags: &[String],
     width: usize,
-    text: Color,
+    tag_fallback: Color,
+    tag_theme: &TagTheme,
 ) -> Text<'static> {
     if tags.is_empty() {
         return base.clone();
     }
 
-    let tag_lines = build_full_tag_lines(tags, width, text);
+    let tag_lines = build_full_tag_lines(tags, width, tag_fallback, tag_theme);
     if tag_lines.is_empty() {
         return base.clone();
     }
@@ -2180,11 +4020,16 @@ fn compose_preview_text(
     Text::from(lines)
 }
 
-fn build_full_tag_lines(tags: &[String], width: usize, text: Color) -> Vec<Line<'static>> {
+fn build_full_tag_lines(
+    tags: &[String],
+    width: usize,
+    tag_fallback: Color,
+    tag_theme: &TagTheme,
+) -> Vec<Line<'static>> {
     if tags.is_empty() || width == 0 {
         return Vec::new();
     }
-    let segments = build_tag_segments(tags, text);
+    let segments = build_tag_segments(tags, tag_fallback, tag_theme);
     wrap_tag_segments(&segments, width)
 }
 
@@ -2229,14 +4074,21 @@ fn wrap_tag_segments(segments: &[TagSegment], width: usize) -> Vec<Line<'static>
     lines
 }
 
-fn tag_color(tag: &str, fallback: Color) -> Color {
+fn tag_color(tag: &str, fallback: Color, theme: &TagTheme) -> Color {
+    if let Some(override_color) = resolve_tag_override(theme, tag) {
+        return override_color;
+    }
     let mut hash = 2166136261u32;
     for byte in tag.as_bytes() {
         hash ^= *byte as u32;
         hash = hash.wrapping_mul(16777619);
     }
     let hue = (hash % 360) as f32;
-    hsl_to_rgb(hue, 0.6, 0.55).unwrap_or(fallback)
+    let (sat, light) = theme.palette.sat_light();
+    match hsl_to_rgb(hue, sat, light) {
+        Some(base) => ensure_min_contrast(hue, sat, light, base, fallback),
+        None => fallback,
+    }
 }
 
 fn hsl_to_rgb(hue: f32, sat: f32, light: f32) -> Option<Color> {
@@ -2289,7 +4141,29 @@ fn build_placeholder_text(
     Text::from(lines)
 }
 
-fn build_preview_text(path: &str, accent: Color, muted: Color, text: Color) -> Text<'static> {
+const PREVIEW_MAX_READ_BYTES: u64 = 1 << 20;
+const BINARY_SNIFF_BYTES: usize = 8192;
+
+fn read_preview_file(path: &Path) -> Option<String> {
+    let mut file = fs::File::open(path).ok()?;
+    let mut buffer = Vec::new();
+    file.by_ref()
+        .take(PREVIEW_MAX_READ_BYTES)
+        .read_to_end(&mut buffer)
+        .ok()?;
+    if buffer[..buffer.len().min(BINARY_SNIFF_BYTES)].contains(&0) {
+        return None;
+    }
+    String::from_utf8(buffer).ok()
+}
+
+fn build_preview_text(
+    path: &str,
+    accent: Color,
+    muted: Color,
+    text: Color,
+    preview_config: &PreviewConfig,
+) -> Text<'static> {
     let value = Style::default().fg(text);
     let heading = Style::default().fg(accent).add_modifier(Modifier::BOLD);
     let subtle = Style::default().fg(muted);
@@ -2302,20 +4176,342 @@ fn build_preview_text(path: &str, accent: Color, muted: Color, text: Color) -> T
     if path_buf.is_dir() {
         lines.push(Line::from(Span::styled("Contents", heading)));
         if let Some(output) = erd_output(path_buf) {
-            lines.extend(lines_from_ansi_output(&output, value, max_lines));
+            let mut tree_lines = lines_from_ansi_output(&output, value, max_lines);
+            if preview_config.git_status_overlay {
+                let status_map = git_status_map(path_buf);
+                let prefix = repo_relative_dir_prefix(path_buf);
+                tree_lines = overlay_git_status(tree_lines, &status_map, accent, muted, &prefix);
+            }
+            lines.extend(tree_lines);
         } else {
             lines.push(Line::from(Span::styled("erd output not available", subtle)));
         }
     } else {
-        lines.push(Line::from(Span::styled("Not a directory", subtle)));
+        match read_preview_file(path_buf) {
+            Some(contents) => {
+                lines.push(Line::from(Span::styled("Contents", heading)));
+                let highlighted = if preview_config.highlight_enabled {
+                    highlighted_file_lines(path_buf, &contents, preview_config, max_lines)
+                } else {
+                    None
+                };
+                match highlighted {
+                    Some(highlighted) => lines.extend(highlighted),
+                    None => lines.extend(lines_from_ansi_output(&contents, value, max_lines)),
+                }
+            }
+            None => lines.push(Line::from(Span::styled(
+                "Unable to read file (binary or unreadable)",
+                subtle,
+            ))),
+        }
     }
 
     Text::from(lines)
 }
 
-fn build_git_text(path: &str, accent: Color, _muted: Color, text: Color) -> Option<Text<'static>> {
-    let heading = Style::default().fg(accent).add_modifier(Modifier::BOLD);
+fn is_image_path(path: &Path) -> bool {
+    let ext = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase());
+    matches!(
+        ext.as_deref(),
+        Some("png") | Some("jpg") | Some("jpeg") | Some("gif") | Some("webp") | Some("bmp")
+    )
+}
+
+fn build_image_preview_text(path: &str, image: &ImagePreview, text: Color) -> Text<'static> {
     let value = Style::default().fg(text);
+    let mut lines = build_path_lines(path, value);
+    lines.push(Line::from(""));
+    lines.extend(image.half_block.lines.clone());
+    Text::from(lines)
+}
+
+fn image_preview_cell_dims(area: Rect) -> (u32, u32) {
+    let cols = area.width.saturating_sub(2) as u32;
+    let rows = area.height.saturating_sub(2) as u32;
+    if cols == 0 || rows == 0 {
+        (IMAGE_PREVIEW_COLS, IMAGE_PREVIEW_ROWS)
+    } else {
+        (cols, rows)
+    }
+}
+
+fn build_image_preview(path: &Path, cols: u32, rows: u32) -> Option<ImagePreview> {
+    let decoded = image::open(path).ok()?;
+    let pixel_rows = rows * 2;
+    let thumb: RgbaImage = decoded
+        .resize(cols, pixel_rows, FilterType::Triangle)
+        .to_rgba8();
+
+    let half_block = Text::from(half_block_lines(&thumb));
+    let kitty_escape = if terminal_supports_kitty() {
+        Some(kitty_graphics_escape(&thumb))
+    } else {
+        None
+    };
+
+    Some(ImagePreview {
+        half_block,
+        kitty_escape,
+    })
+}
+
+fn half_block_lines(image: &RgbaImage) -> Vec<Line<'static>> {
+    let (width, height) = image.dimensions();
+    let mut lines = Vec::new();
+    let mut row = 0u32;
+    while row < height {
+        let mut spans = Vec::with_capacity(width as usize);
+        for col in 0..width {
+            let top = image.get_pixel(col, row);
+            let bottom = if row + 1 < height {
+                image.get_pixel(col, row + 1)
+            } else {
+                top
+            };
+            let fg = Color::Rgb(top[0], top[1], top[2]);
+            let bg = Color::Rgb(bottom[0], bottom[1], bottom[2]);
+            spans.push(Span::styled("\u{2580}", Style::default().fg(fg).bg(bg)));
+        }
+        lines.push(Line::from(spans));
+        row += 2;
+    }
+    lines
+}
+
+fn terminal_supports_kitty() -> bool {
+    env::var("TERM")
+        .map(|term| term.contains("kitty"))
+        .unwrap_or(false)
+        || env::var("TERM_PROGRAM")
+            .map(|term| {
+                let term = term.to_lowercase();
+                term.contains("kitty") || term.contains("wezterm") || term.contains("ghostty")
+            })
+            .unwrap_or(false)
+        || env::var("KITTY_WINDOW_ID").is_ok()
+}
+
+fn kitty_graphics_escape(image: &RgbaImage) -> String {
+    let (width, height) = image.dimensions();
+    let encoded = BASE64.encode(image.as_raw());
+    format!(
+        "\x1b_Gf=32,s={},v={},a=T,t=d;{}\x1b\\",
+        width, height, encoded
+    )
+}
+
+fn syntax_set() -> &'static SyntaxSet {
+    static SET: OnceLock<SyntaxSet> = OnceLock::new();
+    SET.get_or_init(SyntaxSet::load_defaults_newlines)
+}
+
+fn theme_set() -> &'static ThemeSet {
+    static SET: OnceLock<ThemeSet> = OnceLock::new();
+    SET.get_or_init(ThemeSet::load_defaults)
+}
+
+fn resolve_theme(name: &str) -> &'static SyntectTheme {
+    theme_set()
+        .themes
+        .get(name)
+        .or_else(|| theme_set().themes.get("base16-ocean.dark"))
+        .or_else(|| theme_set().themes.values().next())
+        .expect("syntect ships at least one default theme")
+}
+
+fn highlighted_file_lines(
+    path: &Path,
+    contents: &str,
+    preview_config: &PreviewConfig,
+    max_lines: usize,
+) -> Option<Vec<Line<'static>>> {
+    let syntax_set = syntax_set();
+    let syntax = syntax_set
+        .find_syntax_for_file(path)
+        .ok()
+        .flatten()
+        .or_else(|| syntax_set.find_syntax_by_first_line(contents.lines().next().unwrap_or("")))?;
+    let theme = resolve_theme(&preview_config.theme_name);
+    let mut highlighter = HighlightLines::new(syntax, theme);
+
+    let mut lines = Vec::with_capacity(max_lines);
+    for line in LinesWithEndings::from(contents).take(max_lines) {
+        let ranges = highlighter.highlight_line(line, syntax_set).ok()?;
+        let spans: Vec<Span<'static>> = ranges
+            .into_iter()
+            .map(|(style, text)| {
+                let color = Color::Rgb(style.foreground.r, style.foreground.g, style.foreground.b);
+                Span::styled(
+                    text.trim_end_matches(['\n', '\r']).to_string(),
+                    Style::default().fg(color),
+                )
+            })
+            .collect();
+        lines.push(Line::from(spans));
+    }
+    Some(lines)
+}
+
+fn git_repo_cache() -> &'static Mutex<HashMap<PathBuf, Repository>> {
+    static CACHE: OnceLock<Mutex<HashMap<PathBuf, Repository>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn with_repo<T>(repo_dir: &Path, f: impl FnOnce(&Repository) -> Option<T>) -> Option<T> {
+    if let Ok(cache) = git_repo_cache().lock() {
+        if let Some((_, repo)) = cache
+            .iter()
+            .find(|(workdir, _)| repo_dir.starts_with(workdir.as_path()))
+        {
+            return f(repo);
+        }
+    }
+
+    let repo = Repository::discover(repo_dir).ok()?;
+    let workdir = repo.workdir()?.to_path_buf();
+    let mut cache = git_repo_cache().lock().ok()?;
+    let repo = cache.entry(workdir).or_insert(repo);
+    f(repo)
+}
+
+fn git_branch_heading(repo: &Repository) -> Option<String> {
+    let head = repo.head().ok()?;
+    let name = head.shorthand().unwrap_or("HEAD").to_string();
+    if !head.is_branch() {
+        return Some(name);
+    }
+    let branch = git2::Branch::wrap(head);
+    match branch.upstream() {
+        Ok(upstream) => match upstream.name().ok().flatten() {
+            Some(upstream_name) => Some(format!("{}...{}", name, upstream_name)),
+            None => Some(name),
+        },
+        Err(_) => Some(name),
+    }
+}
+
+fn format_relative_time(time: git2::Time) -> String {
+    format_relative_epoch_secs(time.seconds())
+}
+
+fn format_relative_epoch_secs(epoch_secs: i64) -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as i64)
+        .unwrap_or(0);
+    let delta = (now - epoch_secs).max(0);
+    let (value, unit) = if delta < 60 {
+        (delta.max(1), "second")
+    } else if delta < 3600 {
+        (delta / 60, "minute")
+    } else if delta < 86400 {
+        (delta / 3600, "hour")
+    } else if delta < 86400 * 30 {
+        (delta / 86400, "day")
+    } else if delta < 86400 * 365 {
+        (delta / (86400 * 30), "month")
+    } else {
+        (delta / (86400 * 365), "year")
+    };
+    if value == 1 {
+        format!("{} {} ago", value, unit)
+    } else {
+        format!("{} {}s ago", value, unit)
+    }
+}
+
+fn git_recent_commit_oids(repo: &Repository, max: usize) -> Vec<git2::Oid> {
+    let mut revwalk = match repo.revwalk() {
+        Ok(revwalk) => revwalk,
+        Err(_) => return Vec::new(),
+    };
+    if revwalk.push_head().is_err() {
+        return Vec::new();
+    }
+    revwalk
+        .filter_map(|oid| oid.ok())
+        .take(max)
+        .collect()
+}
+
+fn commit_diff_text(repo: &Repository, commit: &git2::Commit) -> String {
+    let tree = match commit.tree() {
+        Ok(tree) => tree,
+        Err(_) => return String::new(),
+    };
+    let parent_tree = commit.parent(0).ok().and_then(|parent| parent.tree().ok());
+    match repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None) {
+        Ok(diff) => diff_to_patch_text(&diff),
+        Err(_) => String::new(),
+    }
+}
+
+fn merge_side_commits(repo: &Repository, commit: &git2::Commit, max: usize) -> Vec<String> {
+    let (Ok(first_parent), Ok(second_parent)) = (commit.parent_id(0), commit.parent_id(1)) else {
+        return Vec::new();
+    };
+    let Ok(merge_base) = repo.merge_base(first_parent, second_parent) else {
+        return Vec::new();
+    };
+    let mut revwalk = match repo.revwalk() {
+        Ok(revwalk) => revwalk,
+        Err(_) => return Vec::new(),
+    };
+    if revwalk.push(second_parent).is_err() || revwalk.hide(merge_base).is_err() {
+        return Vec::new();
+    }
+    revwalk
+        .filter_map(|oid| oid.ok())
+        .filter_map(|oid| repo.find_commit(oid).ok())
+        .take(max)
+        .map(|commit| {
+            format!(
+                "{} ({})",
+                commit.summary().unwrap_or(""),
+                format_relative_time(commit.time())
+            )
+        })
+        .collect()
+}
+
+fn diff_to_patch_text(diff: &git2::Diff) -> String {
+    let mut buf = String::new();
+    let _ = diff.print(DiffFormat::Patch, |_delta, _hunk, line| {
+        match line.origin() {
+            '+' | '-' | ' ' => buf.push(line.origin()),
+            _ => {}
+        }
+        buf.push_str(&String::from_utf8_lossy(line.content()));
+        true
+    });
+    buf
+}
+
+fn git_untracked_paths(repo: &Repository) -> Vec<String> {
+    let mut opts = StatusOptions::new();
+    opts.include_untracked(true).recurse_untracked_dirs(true);
+    let statuses = match repo.statuses(Some(&mut opts)) {
+        Ok(statuses) => statuses,
+        Err(_) => return Vec::new(),
+    };
+    statuses
+        .iter()
+        .filter(|entry| entry.status().is_wt_new())
+        .filter_map(|entry| entry.path().map(|path| path.to_string()))
+        .collect()
+}
+
+fn build_git_text(
+    path: &str,
+    theme: &Theme,
+    expanded_commits: &HashSet<String>,
+) -> Option<(Text<'static>, Vec<(usize, String)>)> {
+    let heading = Style::default().fg(theme.accent).add_modifier(Modifier::BOLD);
+    let value = Style::default().fg(theme.text);
     let max_lines = 200usize;
 
     let path_buf = Path::new(path);
@@ -2325,82 +4521,348 @@ fn build_git_text(path: &str, accent: Color, _muted: Color, text: Color) -> Opti
         path_buf.parent()?.to_path_buf()
     };
 
-    let inside = run_git_command_allow_empty(&repo_dir, &["rev-parse", "--is-inside-work-tree"])?;
-    if inside.trim() != "true" {
-        return None;
-    }
+    with_repo(&repo_dir, |repo| {
+        let mut lines = Vec::new();
+        let mut commit_rows = Vec::new();
 
-    let mut lines = Vec::new();
-    if let Some(status_output) = run_git_command_allow_empty(&repo_dir, &["status", "-sb"]) {
-        if let Some(first_line) = status_output.lines().next() {
-            let branch = first_line.trim_start_matches("## ");
-            if !branch.trim().is_empty() {
-                lines.push(Line::from(Span::styled(
-                    format!("Branch: {}", branch),
-                    heading,
-                )));
-            }
+        if let Some(branch) = git_branch_heading(repo) {
+            lines.push(Line::from(Span::styled(
+                format!("Branch: {}", branch),
+                heading,
+            )));
         }
-    }
 
-    if let Some(log_output) =
-        run_git_command_allow_empty(&repo_dir, &["log", "-3", "--pretty=format:%s (%cr)"])
-    {
-        if !log_output.trim().is_empty() {
-            if !lines.is_empty() {
-                lines.push(Line::from(""));
-            }
-            lines.push(Line::from(Span::styled("Recent commits", heading)));
-            lines.extend(lines_from_output(&log_output, value, max_lines));
+        let commit_oids = git_recent_commit_oids(repo, 10);
+        if commit_oids.is_empty() {
+            return None;
         }
-    } else {
-        return None;
-    }
+        lines.push(Line::from(Span::styled("Recent commits", heading)));
+        for oid in commit_oids {
+            let Ok(commit) = repo.find_commit(oid) else {
+                continue;
+            };
+            let oid_hex = oid.to_string();
+            let is_merge = commit.parent_count() > 1;
+            let is_expanded = expanded_commits.contains(&oid_hex);
+            let fold_char = if is_expanded { '\u{25be}' } else { '\u{25b8}' };
+            let summary = commit.summary().unwrap_or("").to_string();
+            let relative = format_relative_time(commit.time());
+            let label = if is_merge {
+                format!("{} Merge: {} ({})", fold_char, summary, relative)
+            } else {
+                format!(
+                    "{} {} {} ({})",
+                    fold_char,
+                    &oid_hex[..7.min(oid_hex.len())],
+                    summary,
+                    relative,
+                )
+            };
+            commit_rows.push((lines.len(), oid_hex));
+            lines.push(Line::from(Span::styled(label, value)));
 
-    if let Some(staged_output) =
-        run_git_command_allow_empty(&repo_dir, &["diff", "--stat", "--cached"])
-    {
-        if !staged_output.trim().is_empty() {
-            if !lines.is_empty() {
-                lines.push(Line::from(""));
+            if !is_expanded {
+                continue;
+            }
+
+            if is_merge {
+                let diff_text = commit_diff_text(repo, &commit);
+                if !diff_text.trim().is_empty() {
+                    lines.push(Line::from(Span::styled("  First-parent changes", heading)));
+                    lines.extend(diff_lines_from_output(&diff_text, theme, max_lines));
+                }
+                let merged = merge_side_commits(repo, &commit, max_lines);
+                if !merged.is_empty() {
+                    lines.push(Line::from(Span::styled("  Merged commits", heading)));
+                    lines.extend(lines_from_output(&merged.join("\n"), value, max_lines));
+                }
+            } else {
+                let diff_text = commit_diff_text(repo, &commit);
+                if !diff_text.trim().is_empty() {
+                    lines.extend(diff_lines_from_output(&diff_text, theme, max_lines));
+                }
             }
-            lines.push(Line::from(Span::styled("Staged changes", heading)));
-            lines.extend(lines_from_output(&staged_output, value, max_lines));
         }
-    }
 
-    if let Some(unstaged_output) = run_git_command_allow_empty(&repo_dir, &["diff", "--stat"]) {
-        if !unstaged_output.trim().is_empty() {
-            if !lines.is_empty() {
-                lines.push(Line::from(""));
+        if let Ok(head_tree) = repo.head().and_then(|head| head.peel_to_tree()) {
+            if let Ok(diff) = repo.diff_tree_to_index(Some(&head_tree), None, None) {
+                let staged_output = diff_to_patch_text(&diff);
+                if !staged_output.trim().is_empty() {
+                    lines.push(Line::from(""));
+                    lines.push(Line::from(Span::styled("Staged changes", heading)));
+                    lines.extend(diff_lines_from_output(&staged_output, theme, max_lines));
+                }
             }
-            lines.push(Line::from(Span::styled("Unstaged changes", heading)));
-            lines.extend(lines_from_output(&unstaged_output, value, max_lines));
         }
-    }
 
-    if let Some(untracked_output) =
-        run_git_command_allow_empty(&repo_dir, &["ls-files", "--others", "--exclude-standard"])
-    {
-        if !untracked_output.trim().is_empty() {
-            if !lines.is_empty() {
+        if let Ok(diff) = repo.diff_index_to_workdir(None, None) {
+            let unstaged_output = diff_to_patch_text(&diff);
+            if !unstaged_output.trim().is_empty() {
                 lines.push(Line::from(""));
+                lines.push(Line::from(Span::styled("Unstaged changes", heading)));
+                lines.extend(diff_lines_from_output(&unstaged_output, theme, max_lines));
             }
+        }
+
+        let untracked = git_untracked_paths(repo);
+        if !untracked.is_empty() {
+            lines.push(Line::from(""));
             lines.push(Line::from(Span::styled("Untracked", heading)));
-            lines.extend(lines_from_output(&untracked_output, value, max_lines));
+            lines.extend(lines_from_output(&untracked.join("\n"), value, max_lines));
         }
+
+        Some((Text::from(lines), commit_rows))
+    })
+}
+
+fn status_index_char(status: git2::Status) -> char {
+    if status.is_index_new() {
+        'A'
+    } else if status.is_index_modified() {
+        'M'
+    } else if status.is_index_deleted() {
+        'D'
+    } else if status.is_index_renamed() {
+        'R'
+    } else if status.is_index_typechange() {
+        'T'
+    } else {
+        ' '
     }
+}
 
-    if lines.is_empty() {
-        return None;
+fn status_worktree_char(status: git2::Status) -> char {
+    if status.is_wt_new() {
+        '?'
+    } else if status.is_ignored() {
+        '!'
+    } else if status.is_wt_modified() {
+        'M'
+    } else if status.is_wt_deleted() {
+        'D'
+    } else if status.is_wt_renamed() {
+        'R'
+    } else if status.is_wt_typechange() {
+        'T'
+    } else {
+        ' '
     }
-    Some(Text::from(lines))
+}
+
+fn status_char_color(ch: char, accent: Color, muted: Color) -> Color {
+    match ch {
+        'A' => Color::Rgb(100, 200, 120),
+        'M' => Color::Rgb(230, 200, 80),
+        'D' => Color::Rgb(220, 80, 80),
+        'R' | 'T' => accent,
+        _ => muted,
+    }
+}
+
+fn git_status_map(repo_dir: &Path) -> HashMap<String, (char, char)> {
+    with_repo(repo_dir, |repo| {
+        let mut opts = StatusOptions::new();
+        opts.include_untracked(true)
+            .recurse_untracked_dirs(true)
+            .include_ignored(true);
+        let statuses = repo.statuses(Some(&mut opts)).ok()?;
+        let mut map = HashMap::new();
+        for entry in statuses.iter() {
+            let Some(path) = entry.path() else {
+                continue;
+            };
+            let status = entry.status();
+            map.insert(
+                path.to_string(),
+                (status_index_char(status), status_worktree_char(status)),
+            );
+        }
+        Some(map)
+    })
+    .unwrap_or_default()
+}
+
+fn repo_relative_dir_prefix(dir: &Path) -> String {
+    with_repo(dir, |repo| {
+        let workdir = repo.workdir()?;
+        let relative = dir.strip_prefix(workdir).ok()?;
+        Some(relative.to_string_lossy().replace(std::path::MAIN_SEPARATOR, "/"))
+    })
+    .unwrap_or_default()
+}
+
+fn tree_line_depth(content: &str) -> usize {
+    const INDENT_CHARS: &[char] = &['│', '├', '└', '─', ' '];
+    let mut count = 0usize;
+    for ch in content.chars() {
+        if INDENT_CHARS.contains(&ch) {
+            count += 1;
+        } else {
+            break;
+        }
+    }
+    count / 4
+}
+
+fn overlay_git_status(
+    lines: Vec<Line<'static>>,
+    status_map: &HashMap<String, (char, char)>,
+    accent: Color,
+    muted: Color,
+    base_prefix: &str,
+) -> Vec<Line<'static>> {
+    if status_map.is_empty() {
+        return lines;
+    }
+
+    let mut dir_stack: Vec<(usize, String)> = Vec::new();
+
+    lines
+        .into_iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let content: String = line
+                .spans
+                .iter()
+                .map(|span| span.content.as_ref())
+                .collect();
+            let trimmed_end = content.trim_end();
+            let is_dir_entry = trimmed_end.ends_with('/');
+            let trimmed = trimmed_end.trim_end_matches('/');
+            let token = trimmed.rsplit(char::is_whitespace).next().unwrap_or("");
+            let depth = tree_line_depth(&content);
+
+            while dir_stack.last().is_some_and(|(d, _)| *d >= depth) {
+                dir_stack.pop();
+            }
+
+            // The first line is the root directory itself (already covered by
+            // base_prefix); don't append its own name on top of the prefix.
+            let full_path = if index == 0 {
+                base_prefix.to_string()
+            } else {
+                let mut path = base_prefix.to_string();
+                for (_, name) in &dir_stack {
+                    if !path.is_empty() {
+                        path.push('/');
+                    }
+                    path.push_str(name);
+                }
+                if !token.is_empty() {
+                    if !path.is_empty() {
+                        path.push('/');
+                    }
+                    path.push_str(token);
+                }
+                path
+            };
+
+            if index > 0 && is_dir_entry && !token.is_empty() {
+                dir_stack.push((depth, token.to_string()));
+            }
+
+            match status_map.get(&full_path) {
+                Some((index_code, worktree_code)) => {
+                    let mut spans = vec![
+                        Span::styled(
+                            index_code.to_string(),
+                            Style::default().fg(status_char_color(*index_code, accent, muted)),
+                        ),
+                        Span::styled(
+                            worktree_code.to_string(),
+                            Style::default().fg(status_char_color(*worktree_code, accent, muted)),
+                        ),
+                        Span::raw(" "),
+                    ];
+                    spans.extend(line.spans);
+                    Line::from(spans)
+                }
+                None => line,
+            }
+        })
+        .collect()
 }
 
 fn build_path_lines(path: &str, value: Style) -> Vec<Line<'static>> {
     vec![Line::from(Span::styled(path.to_string(), value))]
 }
 
+#[cfg(unix)]
+fn symbolic_permissions(mode: u32) -> String {
+    let file_type = match mode & 0o170000 {
+        0o040000 => 'd',
+        0o120000 => 'l',
+        0o010000 => 'p',
+        0o020000 => 'c',
+        0o060000 => 'b',
+        0o140000 => 's',
+        _ => '-',
+    };
+
+    let rwx = |read: u32, write: u32, exec: u32, special: u32, set_char: char| -> String {
+        let r = if mode & read != 0 { 'r' } else { '-' };
+        let w = if mode & write != 0 { 'w' } else { '-' };
+        let x = match (mode & exec != 0, mode & special != 0) {
+            (true, true) => set_char,
+            (true, false) => 'x',
+            (false, true) => set_char.to_ascii_uppercase(),
+            (false, false) => '-',
+        };
+        format!("{}{}{}", r, w, x)
+    };
+
+    let owner = rwx(0o400, 0o200, 0o100, 0o4000, 's');
+    let group = rwx(0o040, 0o020, 0o010, 0o2000, 's');
+    let other = rwx(0o004, 0o002, 0o001, 0o1000, 't');
+    format!("{}{}{}{}", file_type, owner, group, other)
+}
+
+fn human_readable_size(bytes: u64) -> String {
+    const UNITS: [&str; 6] = ["B", "K", "M", "G", "T", "P"];
+    if bytes < 1024 {
+        return format!("{}B", bytes);
+    }
+    let mut size = bytes as f64;
+    let mut unit_index = 0;
+    while size >= 1024.0 && unit_index < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit_index += 1;
+    }
+    format!("{:.1}{}", size, UNITS[unit_index])
+}
+
+#[cfg(unix)]
+fn build_metadata_footer(path: &str, muted: Color) -> Option<Line<'static>> {
+    use std::os::unix::fs::MetadataExt;
+
+    let metadata = fs::symlink_metadata(path).ok()?;
+    let permissions = symbolic_permissions(metadata.mode());
+    let owner = uzers::get_user_by_uid(metadata.uid())
+        .map(|user| user.name().to_string_lossy().into_owned())
+        .unwrap_or_else(|| "-".to_string());
+    let group = uzers::get_group_by_gid(metadata.gid())
+        .map(|group| group.name().to_string_lossy().into_owned())
+        .unwrap_or_else(|| "-".to_string());
+    let size = human_readable_size(metadata.size());
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+        .map(|duration| format_relative_epoch_secs(duration.as_secs() as i64))
+        .unwrap_or_else(|| "-".to_string());
+
+    let line = format!(
+        "{}  {:<8} {:<8}  {:>8}  {}",
+        permissions, owner, group, size, modified
+    );
+    Some(Line::from(Span::styled(line, Style::default().fg(muted))))
+}
+
+#[cfg(not(unix))]
+fn build_metadata_footer(_path: &str, _muted: Color) -> Option<Line<'static>> {
+    None
+}
+
 fn erd_output(path: &Path) -> Option<String> {
     let path_str = path.to_string_lossy().to_string();
     let (mut args, used_default) = erd_args();
@@ -2476,6 +4938,53 @@ fn lines_from_output(output: &str, style: Style, max_lines: usize) -> Vec<Line<'
         .collect()
 }
 
+fn diff_lines_from_output(output: &str, theme: &Theme, max_lines: usize) -> Vec<Line<'static>> {
+    let addition = Style::default().fg(theme.diff_add);
+    let deletion = Style::default().fg(theme.diff_del);
+    let hunk_header = Style::default().fg(theme.text).add_modifier(Modifier::BOLD);
+    let header = Style::default().fg(theme.text).add_modifier(Modifier::DIM);
+    let context = Style::default().fg(theme.text).add_modifier(Modifier::DIM);
+    let plain = Style::default().fg(theme.text);
+
+    output
+        .lines()
+        .take(max_lines)
+        .map(|line| {
+            let style = if line.starts_with("@@") {
+                hunk_header
+            } else if line.starts_with("+++") || line.starts_with("---") {
+                header
+            } else if line.starts_with("diff ") || line.starts_with("index ") {
+                header
+            } else if line.starts_with('+') {
+                addition
+            } else if line.starts_with('-') {
+                deletion
+            } else if line.starts_with(' ') {
+                context
+            } else {
+                plain
+            };
+            Line::from(Span::styled(line.to_string(), style))
+        })
+        .collect()
+}
+
+fn hunk_boundaries(text: &Text<'static>) -> Vec<usize> {
+    text.lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let content: String = line.spans.iter().map(|span| span.content.as_ref()).collect();
+            if content.starts_with("@@") {
+                Some(index)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
 fn lines_from_ansi_output(output: &str, style: Style, max_lines: usize) -> Vec<Line<'static>> {
     let text_result = output.as_bytes().to_vec().into_text();
     let Ok(text) = text_result else {
@@ -2513,27 +5022,6 @@ fn run_command_output(
     }
 }
 
-fn run_git_command_allow_empty(repo_dir: &Path, args: &[&str]) -> Option<String> {
-    let output = Command::new("git")
-        .arg("-C")
-        .arg(repo_dir)
-        .arg("-c")
-        .arg("color.ui=never")
-        .args(args)
-        .env("NO_COLOR", "1")
-        .output()
-        .ok()?;
-
-    if !output.status.success() {
-        return None;
-    }
-    Some(
-        String::from_utf8_lossy(&output.stdout)
-            .trim_end()
-            .to_string(),
-    )
-}
-
 fn render_side_panels(
     frame: &mut ratatui::Frame,
     area: Rect,
@@ -2541,11 +5029,27 @@ fn render_side_panels(
     git: Option<&Text<'static>>,
     preview_title: &str,
     focus: Focus,
-    accent: Color,
-    text: Color,
+    theme: &Theme,
     preview_scroll: u16,
     git_scroll: u16,
+    metadata_footer: Option<&Line<'static>>,
 ) {
+    let accent = theme.accent;
+    let text = theme.text;
+    let (area, footer_area) = match metadata_footer {
+        Some(_) => {
+            let split = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(3), Constraint::Length(1)])
+                .split(area);
+            (split[0], Some(split[1]))
+        }
+        None => (area, None),
+    };
+    if let (Some(footer_area), Some(footer)) = (footer_area, metadata_footer) {
+        let footer_paragraph = Paragraph::new(footer.clone()).alignment(Alignment::Left);
+        frame.render_widget(footer_paragraph, footer_area);
+    }
     let preview_focused = matches!(focus, Focus::Preview | Focus::TagEdit);
     let git_focused = focus == Focus::Git;
     let preview_border_style = if preview_focused {
@@ -2581,7 +5085,18 @@ fn render_side_panels(
             .wrap(Wrap { trim: false });
         frame.render_widget(preview_paragraph, chunks[0]);
 
-        let git_title = if git_focused { "* Git" } else { "Git" };
+        let hunks = hunk_boundaries(git);
+        let git_base = if git_focused { "* Git" } else { "Git" };
+        let git_title = if !hunks.is_empty() {
+            let current = hunks
+                .iter()
+                .filter(|&&h| h <= git_scroll as usize)
+                .count()
+                .max(1);
+            format!("{} [{}/{}]", git_base, current, hunks.len())
+        } else {
+            git_base.to_string()
+        };
         let git_title = Span::styled(git_title, Style::default().fg(text));
         let git_paragraph = Paragraph::new(git.clone())
             .block(